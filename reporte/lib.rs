@@ -8,7 +8,13 @@
 mod reporte {
     use ink::prelude::collections::BTreeMap;
     use ink::prelude::vec::Vec;
-    use sistema_votacion::{Error, RolUsuario, SistemaVotacionRef, Usuario};
+    use sistema_votacion::{
+        Categoria, Distrito, Error, Lista, MayoriaRequerida, ModoEleccion, PoliticaDesempate,
+        ReglaDistrital, RolUsuario, SistemaVotacionRef, Usuario,
+    };
+
+    //en stv los votos se cuentan en millonesimas para poder transferir fracciones del excedente
+    const ESCALA_STV: u64 = 1_000_000;
 
     #[ink(storage)]
     pub struct Reporte {
@@ -31,7 +37,20 @@ mod reporte {
         }
 
         #[cfg(test)]
-        fn get_votantes(&self, _id: u64) -> Result<Vec<Usuario>, Error> {
+        fn get_votantes(&self, id: u64) -> Result<Vec<Usuario>, Error> {
+            //las elecciones 6 y 21 tienen un padron de 100 votantes
+            if id == 6 || id == 21 {
+                return Ok((0..100)
+                    .map(|i| {
+                        Usuario::new(
+                            AccountId::from([i; 32]),
+                            "Votante".to_string(),
+                            "mail".to_string(),
+                            RolUsuario::Votante,
+                        )
+                    })
+                    .collect());
+            }
             let usuario1 = Usuario::new(
                 AccountId::from([0x1; 32]),
                 "Agustin".to_string(),
@@ -81,13 +100,318 @@ mod reporte {
         }
 
         #[cfg(test)]
-        fn get_candidatos(&self, _id: u64) -> Result<BTreeMap<AccountId, u64>, Error> {
+        fn get_candidatos(&self, id: u64) -> Result<BTreeMap<AccountId, u64>, Error> {
+            //en las elecciones 11 a 14 y en la 20 los candidatos 1 y 2 empatan en el primer lugar
             let mut candidatos = BTreeMap::new();
             candidatos.insert(AccountId::from([0x1; 32]), 10);
-            candidatos.insert(AccountId::from([0x2; 32]), 5);
+            let votos_candidato2 = if (11..=14).contains(&id) || id == 20 {
+                10
+            } else {
+                5
+            };
+            candidatos.insert(AccountId::from([0x2; 32]), votos_candidato2);
             candidatos.insert(AccountId::from([0x3; 32]), 3);
             Ok(candidatos)
         }
+
+        #[cfg(test)]
+        fn get_boletas_preferenciales(&self, id: u64) -> Result<Vec<Vec<AccountId>>, Error> {
+            let candidato1 = AccountId::from([0x1; 32]);
+            let candidato2 = AccountId::from([0x2; 32]);
+            let candidato3 = AccountId::from([0x3; 32]);
+            //la eleccion 4 tiene un ciclo de condorcet: 1 le gana a 2, 2 le gana a 3 y 3 le gana a 1
+            if id == 4 {
+                let mut boletas = vec![vec![candidato1, candidato2, candidato3]; 3];
+                boletas.extend(vec![vec![candidato2, candidato3, candidato1]; 2]);
+                boletas.extend(vec![vec![candidato3, candidato1, candidato2]; 2]);
+                return Ok(boletas);
+            }
+            //en la eleccion 23 los candidatos 1 y 2 empatan en puntos de borda
+            if id == 23 {
+                return Ok(vec![
+                    vec![candidato1, candidato2, candidato3],
+                    vec![candidato2, candidato1, candidato3],
+                ]);
+            }
+            //en la eleccion 5 el excedente del candidato 1 le alcanza al candidato 3 para llegar a la cuota
+            if id == 5 {
+                let mut boletas = vec![vec![candidato1, candidato3, candidato2]; 5];
+                boletas.extend(vec![vec![candidato2, candidato3, candidato1]; 2]);
+                boletas.push(vec![candidato3, candidato2, candidato1]);
+                return Ok(boletas);
+            }
+            Ok(vec![
+                vec![candidato1, candidato2, candidato3],
+                vec![candidato1, candidato2, candidato3],
+                vec![candidato2, candidato1, candidato3],
+                vec![candidato2, candidato3, candidato1],
+                vec![candidato3, candidato2, candidato1],
+            ])
+        }
+
+        #[cfg(test)]
+        fn get_modo_eleccion(&self, id: u64) -> Result<ModoEleccion, Error> {
+            //la eleccion 3 es de puntaje con segunda vuelta star, la 5 es stv con dos bancas, la 6 es por listas, la 7 es una consulta, la 8 es de boleta unica, la 9 es cuadratica, la 10 es acumulativa con dos bancas, la 20 es preferencial, la 23 es de borda y el resto de mayoria
+            match id {
+                3 => Ok(ModoEleccion::Puntaje {
+                    maximo: 5,
+                    star: true,
+                }),
+                5 => Ok(ModoEleccion::Stv { bancas: 2 }),
+                6 => Ok(ModoEleccion::Listas {
+                    bancas: 5,
+                    umbral: 3,
+                }),
+                7 => Ok(ModoEleccion::Consulta),
+                8 => Ok(ModoEleccion::BoletaUnica),
+                9 => Ok(ModoEleccion::Cuadratico { creditos: 100 }),
+                10 => Ok(ModoEleccion::Acumulativo {
+                    votos_por_votante: 3,
+                    bancas: 2,
+                    exige_total: true,
+                }),
+                20 => Ok(ModoEleccion::Preferencial),
+                23 => Ok(ModoEleccion::Borda),
+                _ => Ok(ModoEleccion::Mayoria),
+            }
+        }
+
+        #[cfg(test)]
+        fn get_votos_en_blanco(&self, _id: u64) -> Result<u64, Error> {
+            Ok(1)
+        }
+
+        #[cfg(test)]
+        fn get_votos_nulos(&self, id: u64) -> Result<u64, Error> {
+            //en la eleccion 22 el votante que anulo su voto tiene peso 10
+            match id {
+                22 => Ok(10),
+                _ => Ok(1),
+            }
+        }
+
+        #[cfg(test)]
+        fn get_votos_en_blanco_por_cabeza(&self, _id: u64) -> Result<u64, Error> {
+            Ok(1)
+        }
+
+        #[cfg(test)]
+        fn get_votos_nulos_por_cabeza(&self, _id: u64) -> Result<u64, Error> {
+            Ok(1)
+        }
+
+        #[cfg(test)]
+        fn get_listas(&self, _id: u64) -> Result<Vec<Lista>, Error> {
+            Ok(vec![
+                Lista::new(
+                    "A".to_string(),
+                    vec![
+                        AccountId::from([0x1; 32]),
+                        AccountId::from([0x2; 32]),
+                        AccountId::from([0x3; 32]),
+                    ],
+                    40,
+                ),
+                Lista::new(
+                    "B".to_string(),
+                    vec![AccountId::from([0x4; 32]), AccountId::from([0x5; 32])],
+                    30,
+                ),
+                Lista::new(
+                    "C".to_string(),
+                    vec![AccountId::from([0x6; 32]), AccountId::from([0x7; 32])],
+                    25,
+                ),
+                Lista::new("D".to_string(), vec![AccountId::from([0x8; 32])], 2),
+            ])
+        }
+
+        #[cfg(test)]
+        fn get_boletas_puntaje(&self, _id: u64) -> Result<Vec<Vec<(AccountId, u32)>>, Error> {
+            let candidato1 = AccountId::from([0x1; 32]);
+            let candidato2 = AccountId::from([0x2; 32]);
+            let candidato3 = AccountId::from([0x3; 32]);
+            Ok(vec![
+                vec![(candidato1, 0), (candidato2, 1), (candidato3, 1)],
+                vec![(candidato1, 0), (candidato2, 1), (candidato3, 1)],
+                vec![(candidato1, 2), (candidato2, 3), (candidato3, 1)],
+                vec![(candidato1, 4), (candidato2, 0), (candidato3, 0)],
+                vec![(candidato1, 4), (candidato2, 0), (candidato3, 0)],
+            ])
+        }
+
+        #[cfg(test)]
+        fn get_cargo(&self, _id: u64) -> Result<String, Error> {
+            Ok("¿Aprueba la reforma?".to_string())
+        }
+
+        #[cfg(test)]
+        fn get_opciones(&self, _id: u64) -> Result<Vec<(String, u64)>, Error> {
+            Ok(vec![
+                ("Sí".to_string(), 7),
+                ("No".to_string(), 9),
+                ("Abstención".to_string(), 2),
+            ])
+        }
+
+        #[cfg(test)]
+        fn get_categorias(&self, _id: u64) -> Result<Vec<Categoria>, Error> {
+            let mut presidente = BTreeMap::new();
+            presidente.insert(AccountId::from([0x1; 32]), 10);
+            presidente.insert(AccountId::from([0x2; 32]), 12);
+            let mut gobernador = BTreeMap::new();
+            gobernador.insert(AccountId::from([0x3; 32]), 15);
+            gobernador.insert(AccountId::from([0x4; 32]), 7);
+            Ok(vec![
+                Categoria::new("Presidente".to_string(), presidente),
+                Categoria::new("Gobernador".to_string(), gobernador),
+            ])
+        }
+
+        #[cfg(test)]
+        fn get_votos_escritos(&self, _id: u64) -> Result<BTreeMap<String, u64>, Error> {
+            let mut votos_escritos = BTreeMap::new();
+            votos_escritos.insert("juan perez".to_string(), 2);
+            votos_escritos.insert("ana gomez".to_string(), 4);
+            Ok(votos_escritos)
+        }
+
+        #[cfg(test)]
+        fn get_votos_por_cabeza(&self, _id: u64) -> Result<BTreeMap<AccountId, u64>, Error> {
+            //el candidato 1 tiene menos votantes que el 2 pero con mas peso
+            let mut votos_por_cabeza = BTreeMap::new();
+            votos_por_cabeza.insert(AccountId::from([0x1; 32]), 2);
+            votos_por_cabeza.insert(AccountId::from([0x2; 32]), 5);
+            votos_por_cabeza.insert(AccountId::from([0x3; 32]), 3);
+            Ok(votos_por_cabeza)
+        }
+
+        #[cfg(test)]
+        fn get_creditos_gastados(&self, _id: u64) -> Result<BTreeMap<AccountId, u64>, Error> {
+            //los 10 votos del candidato 1 vienen de dos votantes que le dieron 5 cada uno
+            let mut creditos_gastados = BTreeMap::new();
+            creditos_gastados.insert(AccountId::from([0x1; 32]), 50);
+            creditos_gastados.insert(AccountId::from([0x2; 32]), 25);
+            creditos_gastados.insert(AccountId::from([0x3; 32]), 9);
+            Ok(creditos_gastados)
+        }
+
+        #[cfg(test)]
+        fn get_boletas_acumulativas(
+            &self,
+            _id: u64,
+        ) -> Result<BTreeMap<AccountId, Vec<(AccountId, u32)>>, Error> {
+            //seis votantes con 3 votos cada uno que suman los votos de get_candidatos
+            let candidato1 = AccountId::from([0x1; 32]);
+            let candidato2 = AccountId::from([0x2; 32]);
+            let candidato3 = AccountId::from([0x3; 32]);
+            let mut boletas = BTreeMap::new();
+            boletas.insert(AccountId::from([0x11; 32]), vec![(candidato1, 3)]);
+            boletas.insert(AccountId::from([0x12; 32]), vec![(candidato1, 3)]);
+            boletas.insert(AccountId::from([0x13; 32]), vec![(candidato1, 3)]);
+            boletas.insert(
+                AccountId::from([0x14; 32]),
+                vec![(candidato1, 1), (candidato2, 2)],
+            );
+            boletas.insert(AccountId::from([0x15; 32]), vec![(candidato2, 3)]);
+            boletas.insert(AccountId::from([0x16; 32]), vec![(candidato3, 3)]);
+            Ok(boletas)
+        }
+
+        #[cfg(test)]
+        fn get_politica_desempate(&self, id: u64) -> Result<PoliticaDesempate, Error> {
+            match id {
+                11 | 23 => Ok(PoliticaDesempate::PrimeroEnRegistrarse),
+                12 => Ok(PoliticaDesempate::DecisionAdmin),
+                13 => Ok(PoliticaDesempate::SegundaVuelta),
+                _ => Ok(PoliticaDesempate::DeclararEmpate),
+            }
+        }
+
+        #[cfg(test)]
+        fn get_decision_desempate(&self, id: u64) -> Result<Option<AccountId>, Error> {
+            match id {
+                12 => Ok(Some(AccountId::from([0x1; 32]))),
+                _ => Ok(None),
+            }
+        }
+
+        #[cfg(test)]
+        fn get_segunda_vuelta(&self, id: u64) -> Result<Option<u64>, Error> {
+            match id {
+                13 => Ok(Some(15)),
+                _ => Ok(None),
+            }
+        }
+
+        #[cfg(test)]
+        fn get_orden_candidatos(&self, _id: u64) -> Result<Vec<AccountId>, Error> {
+            Ok(vec![
+                AccountId::from([0x3; 32]),
+                AccountId::from([0x2; 32]),
+                AccountId::from([0x1; 32]),
+            ])
+        }
+
+        #[cfg(test)]
+        fn get_quorum(&self, _id: u64) -> Result<u32, Error> {
+            Ok(50)
+        }
+
+        #[cfg(test)]
+        fn get_mayoria_requerida(&self, id: u64) -> Result<MayoriaRequerida, Error> {
+            match id {
+                2 => Ok(MayoriaRequerida::Absoluta),
+                16 => Ok(MayoriaRequerida::DosTercios),
+                _ => Ok(MayoriaRequerida::Simple),
+            }
+        }
+
+        #[cfg(test)]
+        fn get_distritos(&self, id: u64) -> Result<Vec<Distrito>, Error> {
+            if id != 17 && id != 18 {
+                return Ok(Vec::new());
+            }
+            let mut norte = BTreeMap::new();
+            norte.insert(AccountId::from([0x1; 32]), 6);
+            norte.insert(AccountId::from([0x2; 32]), 4);
+            let mut sur = BTreeMap::new();
+            sur.insert(AccountId::from([0x2; 32]), 5);
+            sur.insert(AccountId::from([0x3; 32]), 5);
+            let mut centro = BTreeMap::new();
+            centro.insert(AccountId::from([0x1; 32]), 2);
+            centro.insert(AccountId::from([0x2; 32]), 7);
+            centro.insert(AccountId::from([0x3; 32]), 1);
+            Ok(vec![
+                Distrito::new("Norte".to_string(), 8, norte),
+                Distrito::new("Sur".to_string(), 3, sur),
+                Distrito::new("Centro".to_string(), 4, centro),
+            ])
+        }
+
+        #[cfg(test)]
+        fn get_fin_revelado(&self, id: u64) -> Result<Option<u64>, Error> {
+            match id {
+                19 => Ok(Some(200000)),
+                _ => Ok(None),
+            }
+        }
+
+        #[cfg(test)]
+        fn get_compromisos_sin_revelar(&self, id: u64) -> Result<Vec<AccountId>, Error> {
+            match id {
+                19 => Ok(vec![AccountId::from([0x4; 32]), AccountId::from([0x5; 32])]),
+                _ => Ok(Vec::new()),
+            }
+        }
+
+        #[cfg(test)]
+        fn get_regla_distrital(&self, id: u64) -> Result<ReglaDistrital, Error> {
+            match id {
+                18 => Ok(ReglaDistrital::Proporcional),
+                _ => Ok(ReglaDistrital::GanadorSeLlevaTodo),
+            }
+        }
         //------------------------------------------------------------------------------------------------------
         #[cfg(not(test))]
         fn get_votantes(&self, id: u64) -> Result<Vec<Usuario>, Error> {
@@ -119,6 +443,134 @@ mod reporte {
             self.sistema_votacion.get_candidatos(id)
         }
 
+        #[cfg(not(test))]
+        fn get_boletas_preferenciales(&self, id: u64) -> Result<Vec<Vec<AccountId>>, Error> {
+            self.sistema_votacion.get_boletas_preferenciales(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_modo_eleccion(&self, id: u64) -> Result<ModoEleccion, Error> {
+            self.sistema_votacion.get_modo_eleccion(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_votos_en_blanco(&self, id: u64) -> Result<u64, Error> {
+            self.sistema_votacion.get_votos_en_blanco(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_votos_nulos(&self, id: u64) -> Result<u64, Error> {
+            self.sistema_votacion.get_votos_nulos(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_votos_en_blanco_por_cabeza(&self, id: u64) -> Result<u64, Error> {
+            self.sistema_votacion.get_votos_en_blanco_por_cabeza(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_votos_nulos_por_cabeza(&self, id: u64) -> Result<u64, Error> {
+            self.sistema_votacion.get_votos_nulos_por_cabeza(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_listas(&self, id: u64) -> Result<Vec<Lista>, Error> {
+            self.sistema_votacion.get_listas(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_boletas_puntaje(&self, id: u64) -> Result<Vec<Vec<(AccountId, u32)>>, Error> {
+            self.sistema_votacion.get_boletas_puntaje(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_cargo(&self, id: u64) -> Result<String, Error> {
+            self.sistema_votacion.get_cargo(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_opciones(&self, id: u64) -> Result<Vec<(String, u64)>, Error> {
+            self.sistema_votacion.get_opciones(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_categorias(&self, id: u64) -> Result<Vec<Categoria>, Error> {
+            self.sistema_votacion.get_categorias(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_votos_escritos(&self, id: u64) -> Result<BTreeMap<String, u64>, Error> {
+            self.sistema_votacion.get_votos_escritos(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_votos_por_cabeza(&self, id: u64) -> Result<BTreeMap<AccountId, u64>, Error> {
+            self.sistema_votacion.get_votos_por_cabeza(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_creditos_gastados(&self, id: u64) -> Result<BTreeMap<AccountId, u64>, Error> {
+            self.sistema_votacion.get_creditos_gastados(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_quorum(&self, id: u64) -> Result<u32, Error> {
+            self.sistema_votacion.get_quorum(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_mayoria_requerida(&self, id: u64) -> Result<MayoriaRequerida, Error> {
+            self.sistema_votacion.get_mayoria_requerida(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_distritos(&self, id: u64) -> Result<Vec<Distrito>, Error> {
+            self.sistema_votacion.get_distritos(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_regla_distrital(&self, id: u64) -> Result<ReglaDistrital, Error> {
+            self.sistema_votacion.get_regla_distrital(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_fin_revelado(&self, id: u64) -> Result<Option<u64>, Error> {
+            self.sistema_votacion.get_fin_revelado(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_compromisos_sin_revelar(&self, id: u64) -> Result<Vec<AccountId>, Error> {
+            self.sistema_votacion.get_compromisos_sin_revelar(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_politica_desempate(&self, id: u64) -> Result<PoliticaDesempate, Error> {
+            self.sistema_votacion.get_politica_desempate(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_decision_desempate(&self, id: u64) -> Result<Option<AccountId>, Error> {
+            self.sistema_votacion.get_decision_desempate(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_segunda_vuelta(&self, id: u64) -> Result<Option<u64>, Error> {
+            self.sistema_votacion.get_segunda_vuelta(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_orden_candidatos(&self, id: u64) -> Result<Vec<AccountId>, Error> {
+            self.sistema_votacion.get_orden_candidatos(id)
+        }
+
+        #[cfg(not(test))]
+        fn get_boletas_acumulativas(
+            &self,
+            id: u64,
+        ) -> Result<BTreeMap<AccountId, Vec<(AccountId, u32)>>, Error> {
+            self.sistema_votacion.get_boletas_acumulativas(id)
+        }
+
         fn generar_reporte_registro_votantes_priv(
             &self,
             id: u64,
@@ -163,6 +615,13 @@ mod reporte {
             if fecha_actual < fecha_cierre {
                 return Err(Error::EleccionAbierta);
             }
+            //con voto secreto el conteo no esta completo hasta que termina el revelado
+            if self
+                .get_fin_revelado(id)?
+                .is_some_and(|fin_revelado| fecha_actual <= fin_revelado)
+            {
+                return Err(Error::EleccionAbierta);
+            }
 
             //traigo la cantidad de votos emitidos desde el contrato sistema_votacion y la cantidad de votantes registrados
             let cantidad_votos_emitidos = self.get_votantes_que_votaron(id)?.len() as u64;
@@ -178,10 +637,21 @@ mod reporte {
                 .checked_div(cantidad_votantes)
                 .ok_or(Error::Overflow)?;
 
+            //los votos en blanco son validos y los nulos no, como en el codigo electoral nacional. Los votos validos se cuentan por votante, igual que los votos emitidos
+            let votos_nulos_por_cabeza = self.get_votos_nulos_por_cabeza(id)?;
+            let votos_validos = cantidad_votos_emitidos
+                .checked_sub(votos_nulos_por_cabeza)
+                .ok_or(Error::Overflow)?;
+
             let reporte_participacion = ReporteParticipacion {
                 nro_eleccion: id,
                 cantidad_votos_emitidos,
                 porcentaje_participacion,
+                votos_validos,
+                votos_en_blanco: self.get_votos_en_blanco(id)?,
+                votos_nulos: self.get_votos_nulos(id)?,
+                votos_en_blanco_por_cabeza: self.get_votos_en_blanco_por_cabeza(id)?,
+                votos_nulos_por_cabeza,
             };
 
             Ok(reporte_participacion)
@@ -196,77 +666,1360 @@ mod reporte {
             self.generar_reporte_participacion_priv(id)
         }
 
-        fn generar_reporte_resultado_priv(&self, id: u64) -> Result<ReporteResultado, Error> {
+        fn generar_reporte_validez_priv(&self, id: u64) -> Result<ReporteValidez, Error> {
             let fecha_cierre = self.get_fecha_fin(id)?;
             let fecha_inicio = self.get_fecha_inicio(id)?;
             let fecha_actual = self.env().block_timestamp();
-
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
             //verifica que la elección ya haya cerrado
             if fecha_actual < fecha_cierre {
                 return Err(Error::EleccionAbierta);
             }
-            //verifica que la elección ya haya iniciado
-            if fecha_actual < fecha_inicio {
-                return Err(Error::EleccionNoActiva);
+            //con voto secreto el conteo no esta completo hasta que termina el revelado
+            if self
+                .get_fin_revelado(id)?
+                .is_some_and(|fin_revelado| fecha_actual <= fin_revelado)
+            {
+                return Err(Error::EleccionAbierta);
             }
 
-            //traigo los resultados de la elección desde el contrato sistema_votacion y los ordeno
-            let resultados_desordenados = self.get_candidatos(id)?;
-            let mut resultados_ordenados = resultados_desordenados.into_iter().collect::<Vec<_>>();
-            resultados_ordenados.sort_by(|a, b| b.1.cmp(&a.1));
+            //solo en mayoria los votos de los candidatos son el conteo que define la mayoria requerida
+            if self.get_modo_eleccion(id)? != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
+            }
 
-            let reporte_resultado = ReporteResultado {
-                nro_eleccion: id,
-                resultados_ordenados,
+            //el quorum se mide igual que la participacion, votos emitidos sobre votantes registrados
+            let cantidad_votos_emitidos = self.get_votantes_que_votaron(id)?.len() as u64;
+            let cantidad_votantes = self.get_votantes(id)?.len() as u64;
+            let quorum = self.get_quorum(id)?;
+            let alcanza_quorum = cantidad_votos_emitidos
+                .checked_mul(100)
+                .ok_or(Error::Overflow)?
+                >= cantidad_votantes
+                    .checked_mul(u64::from(quorum))
+                    .ok_or(Error::Overflow)?;
+
+            //los votos validos son los votos a candidatos, a candidatos escritos y en blanco, todos suman el peso de cada votante
+            let mut resultados = self.get_candidatos(id)?.into_iter().collect::<Vec<_>>();
+            resultados.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+            let votos_escritos = self.get_votos_escritos(id)?;
+            let votos_validos = resultados
+                .iter()
+                .map(|(_, votos)| votos)
+                .chain(votos_escritos.values())
+                .try_fold(self.get_votos_en_blanco(id)?, |total, votos| {
+                    total.checked_add(*votos)
+                })
+                .ok_or(Error::Overflow)?;
+
+            //el mas votado gana si no empata con el segundo ni con un candidato escrito y alcanza la mayoria requerida
+            let mayoria_requerida = self.get_mayoria_requerida(id)?;
+            let votos_segundo = resultados
+                .get(1)
+                .map(|(_, votos)| *votos)
+                .into_iter()
+                .chain(votos_escritos.values().copied())
+                .max()
+                .unwrap_or(0);
+            let ganador = match resultados.first() {
+                Some((primero, votos)) if *votos > votos_segundo => {
+                    let alcanza_mayoria = match mayoria_requerida {
+                        MayoriaRequerida::Simple => true,
+                        MayoriaRequerida::Absoluta => {
+                            votos.checked_mul(2).ok_or(Error::Overflow)? > votos_validos
+                        }
+                        MayoriaRequerida::DosTercios => {
+                            votos.checked_mul(3).ok_or(Error::Overflow)?
+                                >= votos_validos.checked_mul(2).ok_or(Error::Overflow)?
+                        }
+                    };
+                    if alcanza_mayoria {
+                        Some(*primero)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
             };
 
-            Ok(reporte_resultado)
+            //si no se alcanza el quorum la eleccion es nula y no hay ganador
+            Ok(ReporteValidez {
+                nro_eleccion: id,
+                cantidad_votos_emitidos,
+                cantidad_votantes,
+                quorum,
+                valida: alcanza_quorum,
+                mayoria_requerida,
+                votos_validos,
+                ganador: if alcanza_quorum { ganador } else { None },
+            })
         }
 
         #[ink(message)]
-        // Genera un reporte de los resultados de una elección
-        pub fn generar_reporte_resultado(&self, id: u64) -> Result<ReporteResultado, Error> {
-            self.generar_reporte_resultado_priv(id)
+        // Genera un reporte que dice si una elección de mayoría alcanzo el quorum y si alguien alcanzo la mayoria requerida para ganar
+        pub fn generar_reporte_validez(&self, id: u64) -> Result<ReporteValidez, Error> {
+            self.generar_reporte_validez_priv(id)
         }
-    }
 
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    pub struct ReporteRegistroVotantes {
-        nro_eleccion: u64,
-        votantes: Vec<Usuario>,
-    }
+        fn generar_reporte_distritos_priv(&self, id: u64) -> Result<ReporteDistritos, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //con voto secreto el conteo no esta completo hasta que termina el revelado
+            if self
+                .get_fin_revelado(id)?
+                .is_some_and(|fin_revelado| fecha_actual <= fin_revelado)
+            {
+                return Err(Error::EleccionAbierta);
+            }
 
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    pub struct ReporteParticipacion {
-        nro_eleccion: u64,
-        cantidad_votos_emitidos: u64,
-        porcentaje_participacion: u64,
-    }
+            let distritos = self.get_distritos(id)?;
+            if distritos.is_empty() {
+                return Err(Error::DistritoNoExiste);
+            }
+            let regla = self.get_regla_distrital(id)?;
 
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    pub struct ReporteResultado {
-        nro_eleccion: u64,
-        resultados_ordenados: Vec<(AccountId, u64)>,
-    }
+            let mut votos_totales: BTreeMap<AccountId, u64> = BTreeMap::new();
+            let mut electores_totales: BTreeMap<AccountId, u32> = BTreeMap::new();
+            let mut total_electores: u32 = 0;
+            let mut resultados_distritos = Vec::new();
+            for distrito in distritos {
+                let mut resultados_ordenados = distrito
+                    .candidatos()
+                    .iter()
+                    .map(|(id_candidato, votos)| (*id_candidato, *votos))
+                    .collect::<Vec<_>>();
+                resultados_ordenados.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
 
-    #[cfg(test)]
-    mod tests {
+                let electores_por_candidato = match regla {
+                    //el mas votado se lleva todos los electores, si empata el distrito no asigna electores
+                    ReglaDistrital::GanadorSeLlevaTodo => {
+                        let votos_segundo = resultados_ordenados.get(1).map_or(0, |(_, v)| *v);
+                        match resultados_ordenados.first() {
+                            Some((primero, votos)) if *votos > votos_segundo => {
+                                vec![(*primero, distrito.electores())]
+                            }
+                            _ => Vec::new(),
+                        }
+                    }
+                    ReglaDistrital::Proporcional => {
+                        let participantes = resultados_ordenados
+                            .iter()
+                            .map(|(_, votos)| (*votos, distrito.electores() as usize))
+                            .collect::<Vec<_>>();
+                        Self::repartir_dhondt(&participantes, distrito.electores())?
+                            .into_iter()
+                            .zip(resultados_ordenados.iter())
+                            .filter(|(electores, _)| *electores > 0)
+                            .map(|(electores, (id_candidato, _))| (*id_candidato, electores))
+                            .collect()
+                    }
+                };
 
-        use super::*;
+                for (id_candidato, votos) in resultados_ordenados.iter() {
+                    let total = votos_totales.entry(*id_candidato).or_insert(0);
+                    *total = total.checked_add(*votos).ok_or(Error::Overflow)?;
+                }
+                for (id_candidato, electores) in electores_por_candidato.iter() {
+                    let total = electores_totales.entry(*id_candidato).or_insert(0);
+                    *total = total.checked_add(*electores).ok_or(Error::Overflow)?;
+                }
+                total_electores = total_electores
+                    .checked_add(distrito.electores())
+                    .ok_or(Error::Overflow)?;
 
-        #[ink::test]
-        fn test_generar_reporte_registro_votantes() {
-            let reporte = Reporte::new();
+                resultados_distritos.push(ResultadoDistrito {
+                    nombre: distrito.nombre().clone(),
+                    electores: distrito.electores(),
+                    resultados_ordenados,
+                    electores_por_candidato,
+                });
+            }
 
-            assert!(reporte.generar_reporte_registro_votantes(1).is_ok());
-            assert!(reporte.generar_reporte_registro_votantes(3).is_err());
-            let reporte_registro_votantes = ReporteRegistroVotantes {
+            let mut votos_totales = votos_totales.into_iter().collect::<Vec<_>>();
+            votos_totales.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+            let mut electores_totales = electores_totales.into_iter().collect::<Vec<_>>();
+            electores_totales.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+
+            //gana el que junta mas de la mitad de todos los electores
+            let ganador = match electores_totales.first() {
+                Some((primero, electores))
+                    if u64::from(*electores)
+                        .checked_mul(2)
+                        .ok_or(Error::Overflow)?
+                        > u64::from(total_electores) =>
+                {
+                    Some(*primero)
+                }
+                _ => None,
+            };
+
+            Ok(ReporteDistritos {
+                nro_eleccion: id,
+                regla,
+                distritos: resultados_distritos,
+                votos_totales,
+                electores_totales,
+                ganador,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una elección por distritos con los resultados de cada distrito, los electores que gano cada candidato y el ganador del colegio electoral
+        pub fn generar_reporte_distritos(&self, id: u64) -> Result<ReporteDistritos, Error> {
+            self.generar_reporte_distritos_priv(id)
+        }
+
+        fn generar_reporte_voto_secreto_priv(&self, id: u64) -> Result<ReporteVotoSecreto, Error> {
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            let fin_revelado = self
+                .get_fin_revelado(id)?
+                .ok_or(Error::ModoEleccionInvalido)?;
+            //verifica que ya haya terminado el revelado de los votos
+            if fecha_actual <= fin_revelado {
+                return Err(Error::EleccionAbierta);
+            }
+
+            //los votos revelados son los que se sumaron al conteo, el resto de los compromisos no cuenta
+            Ok(ReporteVotoSecreto {
+                nro_eleccion: id,
+                votos_revelados: self.get_votantes_que_votaron(id)?.len() as u64,
+                compromisos_sin_revelar: self.get_compromisos_sin_revelar(id)?,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una elección con voto secreto con los votos revelados y los votantes que no revelaron su compromiso
+        pub fn generar_reporte_voto_secreto(&self, id: u64) -> Result<ReporteVotoSecreto, Error> {
+            self.generar_reporte_voto_secreto_priv(id)
+        }
+
+        fn generar_reporte_resultado_priv(&self, id: u64) -> Result<ReporteResultado, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+            //con voto secreto el conteo no esta completo hasta que termina el revelado
+            if self
+                .get_fin_revelado(id)?
+                .is_some_and(|fin_revelado| fecha_actual <= fin_revelado)
+            {
+                return Err(Error::EleccionAbierta);
+            }
+
+            //traigo los resultados de la elección desde el contrato sistema_votacion y los ordeno
+            let resultados_desordenados = self.get_candidatos(id)?;
+            let mut resultados_ordenados = resultados_desordenados.into_iter().collect::<Vec<_>>();
+            self.ordenar_resultados(id, &mut resultados_ordenados)?;
+
+            //en las elecciones de puntaje los resultados son los puntajes totales y se agregan los promedios y la segunda vuelta
+            let puntaje = match self.get_modo_eleccion(id)? {
+                ModoEleccion::Puntaje { star, .. } => {
+                    Some(self.calcular_resultado_puntaje(id, &resultados_ordenados, star)?)
+                }
+                _ => None,
+            };
+
+            let empate = self.resolver_empate(id, &mut resultados_ordenados)?;
+
+            //cuantos votantes eligieron a cada candidato sin contar el peso de su voto, en el mismo orden que los resultados
+            let cabezas = self.get_votos_por_cabeza(id)?;
+            let votos_por_cabeza = resultados_ordenados
+                .iter()
+                .map(|(candidato, _)| (*candidato, cabezas.get(candidato).copied().unwrap_or(0)))
+                .collect();
+
+            //los candidatos escritos por los votantes se muestran aparte de los registrados
+            let mut votos_escritos = self.get_votos_escritos(id)?.into_iter().collect::<Vec<_>>();
+            votos_escritos.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+
+            let reporte_resultado = ReporteResultado {
+                nro_eleccion: id,
+                resultados_ordenados,
+                votos_por_cabeza,
+                votos_escritos,
+                empate,
+                puntaje,
+                votos_en_blanco: self.get_votos_en_blanco(id)?,
+                votos_nulos: self.get_votos_nulos(id)?,
+            };
+
+            Ok(reporte_resultado)
+        }
+
+        /// Ordena los resultados de mayor a menor, los empatados quedan en el orden en que se registraron los candidatos
+        fn ordenar_resultados(
+            &self,
+            id: u64,
+            resultados: &mut [(AccountId, u64)],
+        ) -> Result<(), Error> {
+            let orden = self.get_orden_candidatos(id)?;
+            let posicion = |candidato: &AccountId| {
+                orden
+                    .iter()
+                    .position(|c| c == candidato)
+                    .unwrap_or(orden.len())
+            };
+            resultados.sort_by(|a, b| {
+                b.1.cmp(&a.1)
+                    .then_with(|| posicion(&a.0).cmp(&posicion(&b.0)))
+            });
+            Ok(())
+        }
+
+        /// Marca el empate en el primer lugar de los resultados y lo resuelve segun la politica de desempate de la eleccion, si queda un ganador lo pone primero
+        fn resolver_empate(
+            &self,
+            id: u64,
+            resultados_ordenados: &mut Vec<(AccountId, u64)>,
+        ) -> Result<Option<Empate>, Error> {
+            //solo se marca el empate si el conteo es el resultado final del modo
+            if !self.get_modo_eleccion(id)?.admite_desempate() {
+                return Ok(None);
+            }
+            let votos_primero = match resultados_ordenados.first() {
+                Some((_, votos)) if *votos > 0 => *votos,
+                _ => return Ok(None),
+            };
+            let candidatos: Vec<AccountId> = resultados_ordenados
+                .iter()
+                .take_while(|(_, votos)| *votos == votos_primero)
+                .map(|(candidato, _)| *candidato)
+                .collect();
+            if candidatos.len() < 2 {
+                return Ok(None);
+            }
+
+            let politica = self.get_politica_desempate(id)?;
+            let (ganador, segunda_vuelta) = match politica {
+                PoliticaDesempate::DeclararEmpate => (None, None),
+                //los empatados ya estan ordenados por registro
+                PoliticaDesempate::PrimeroEnRegistrarse => (candidatos.first().copied(), None),
+                PoliticaDesempate::DecisionAdmin => (self.get_decision_desempate(id)?, None),
+                PoliticaDesempate::SegundaVuelta => (None, self.get_segunda_vuelta(id)?),
+            };
+
+            if let Some(ganador) = ganador {
+                if let Some(pos) = resultados_ordenados.iter().position(|(c, _)| *c == ganador) {
+                    let resultado = resultados_ordenados.remove(pos);
+                    resultados_ordenados.insert(0, resultado);
+                }
+            }
+
+            Ok(Some(Empate {
+                candidatos,
+                politica,
+                ganador,
+                segunda_vuelta,
+            }))
+        }
+
+        fn calcular_resultado_puntaje(
+            &self,
+            id: u64,
+            resultados_ordenados: &[(AccountId, u64)],
+            star: bool,
+        ) -> Result<ResultadoPuntaje, Error> {
+            let boletas = self.get_boletas_puntaje(id)?;
+            let cantidad_boletas = boletas.len() as u64;
+
+            //calculo el promedio de cada candidato en centesimas de punto
+            let mut promedios = Vec::new();
+            for (candidato, total) in resultados_ordenados.iter() {
+                let promedio = total
+                    .checked_mul(100)
+                    .ok_or(Error::Overflow)?
+                    .checked_div(cantidad_boletas)
+                    .unwrap_or(0);
+                promedios.push((*candidato, promedio));
+            }
+
+            //segunda vuelta entre los dos candidatos con mas puntaje, cada boleta es un voto para el finalista que puntuo mas alto
+            let segunda_vuelta = match resultados_ordenados {
+                [(primero, _), (segundo, _), ..] if !boletas.is_empty() => {
+                    let mut votos_primero: u64 = 0;
+                    let mut votos_segundo: u64 = 0;
+                    let mut sin_preferencia: u64 = 0;
+                    for boleta in boletas.iter() {
+                        let puntaje_de = |candidato: &AccountId| {
+                            boleta
+                                .iter()
+                                .find(|(c, _)| c == candidato)
+                                .map_or(0, |(_, puntaje)| *puntaje)
+                        };
+                        let contador = match puntaje_de(primero).cmp(&puntaje_de(segundo)) {
+                            core::cmp::Ordering::Greater => &mut votos_primero,
+                            core::cmp::Ordering::Less => &mut votos_segundo,
+                            core::cmp::Ordering::Equal => &mut sin_preferencia,
+                        };
+                        *contador = contador.checked_add(1).ok_or(Error::Overflow)?;
+                    }
+                    Some(SegundaVueltaPuntaje {
+                        finalistas: vec![(*primero, votos_primero), (*segundo, votos_segundo)],
+                        sin_preferencia,
+                    })
+                }
+                _ => None,
+            };
+
+            let ganador = match (&segunda_vuelta, star) {
+                //con star gana el finalista preferido por mas votantes, si empatan gana el de mayor puntaje total
+                (Some(segunda_vuelta), true)
+                    if segunda_vuelta.finalistas[1].1 > segunda_vuelta.finalistas[0].1 =>
+                {
+                    Some(segunda_vuelta.finalistas[1].0)
+                }
+                _ if boletas.is_empty() => None,
+                _ => resultados_ordenados
+                    .first()
+                    .map(|(candidato, _)| *candidato),
+            };
+
+            Ok(ResultadoPuntaje {
+                promedios,
+                segunda_vuelta,
+                ganador,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de los resultados de una elección
+        pub fn generar_reporte_resultado(&self, id: u64) -> Result<ReporteResultado, Error> {
+            self.generar_reporte_resultado_priv(id)
+        }
+
+        fn generar_reporte_preferencial_priv(&self, id: u64) -> Result<ReportePreferencial, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            //traigo los candidatos y las boletas con las preferencias desde el contrato sistema_votacion
+            let mut en_carrera = self.get_candidatos(id)?.into_keys().collect::<Vec<_>>();
+            let boletas = self.get_boletas_preferenciales(id)?;
+
+            let mut rondas = Vec::new();
+            let mut ganador = None;
+            while !en_carrera.is_empty() && !boletas.is_empty() {
+                //cada boleta suma un voto a su preferencia mas alta entre los candidatos que siguen en carrera
+                let mut conteo = en_carrera
+                    .iter()
+                    .map(|c| (*c, 0u64))
+                    .collect::<BTreeMap<_, _>>();
+                let mut votos_validos: u64 = 0;
+                for boleta in boletas.iter() {
+                    if let Some(preferido) = boleta.iter().find(|c| en_carrera.contains(c)) {
+                        if let Some(votos) = conteo.get_mut(preferido) {
+                            *votos = votos.checked_add(1).ok_or(Error::Overflow)?;
+                            votos_validos = votos_validos.checked_add(1).ok_or(Error::Overflow)?;
+                        }
+                    }
+                }
+                let mut conteo_ordenado = conteo.into_iter().collect::<Vec<_>>();
+                conteo_ordenado.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+
+                //gana el primero si tiene mayoria absoluta o si es el unico que queda en carrera
+                let (primero, votos_primero) = conteo_ordenado[0];
+                let mayoria_absoluta =
+                    votos_primero.checked_mul(2).ok_or(Error::Overflow)? > votos_validos;
+                let eliminado = if mayoria_absoluta || en_carrera.len() == 1 {
+                    ganador = Some(primero);
+                    None
+                } else {
+                    //si nadie tiene mayoria se elimina al que tiene menos votos
+                    let (ultimo, _) = conteo_ordenado[conteo_ordenado.len() - 1];
+                    en_carrera.retain(|c| *c != ultimo);
+                    Some(ultimo)
+                };
+
+                rondas.push(RondaPreferencial {
+                    nro_ronda: rondas.len() as u64,
+                    conteo: conteo_ordenado,
+                    eliminado,
+                });
+                if ganador.is_some() {
+                    break;
+                }
+            }
+
+            Ok(ReportePreferencial {
+                nro_eleccion: id,
+                rondas,
+                ganador,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una elección preferencial con el conteo y el candidato eliminado en cada ronda de la segunda vuelta instantanea
+        pub fn generar_reporte_preferencial(&self, id: u64) -> Result<ReportePreferencial, Error> {
+            self.generar_reporte_preferencial_priv(id)
+        }
+
+        fn generar_reporte_borda_priv(&self, id: u64) -> Result<ReporteBorda, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            //traigo los candidatos y las boletas con las preferencias desde el contrato sistema_votacion
+            let mut puntos = self
+                .get_candidatos(id)?
+                .into_keys()
+                .map(|c| (c, 0u64))
+                .collect::<BTreeMap<_, _>>();
+            let boletas = self.get_boletas_preferenciales(id)?;
+
+            //cada boleta le da n-1 puntos a la primera preferencia, n-2 a la segunda y asi hasta 0 a la ultima
+            for boleta in boletas.iter() {
+                for (posicion, candidato) in boleta.iter().enumerate() {
+                    if let Some(total) = puntos.get_mut(candidato) {
+                        let puntos_posicion = (boleta.len() - 1 - posicion) as u64;
+                        *total = total.checked_add(puntos_posicion).ok_or(Error::Overflow)?;
+                    }
+                }
+            }
+            let mut puntos_ordenados = puntos.into_iter().collect::<Vec<_>>();
+            self.ordenar_resultados(id, &mut puntos_ordenados)?;
+
+            //un empate en puntos se resuelve con la politica de desempate de la eleccion
+            let empate = self.resolver_empate(id, &mut puntos_ordenados)?;
+            let ganador = match &empate {
+                _ if boletas.is_empty() => None,
+                Some(empate) => empate.ganador,
+                None => puntos_ordenados.first().map(|(candidato, _)| *candidato),
+            };
+
+            Ok(ReporteBorda {
+                nro_eleccion: id,
+                puntos_ordenados,
+                empate,
+                ganador,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte con los puntos de borda de cada candidato a partir de las boletas con preferencias
+        pub fn generar_reporte_borda(&self, id: u64) -> Result<ReporteBorda, Error> {
+            self.generar_reporte_borda_priv(id)
+        }
+
+        fn generar_reporte_condorcet_priv(&self, id: u64) -> Result<ReporteCondorcet, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            //traigo los candidatos y las boletas con las preferencias desde el contrato sistema_votacion, las filas y columnas de las matrices siguen el orden de candidatos
+            let candidatos = self.get_candidatos(id)?.into_keys().collect::<Vec<_>>();
+            let boletas = self.get_boletas_preferenciales(id)?;
+            let n = candidatos.len();
+
+            //matriz de preferencias: en la fila i columna j la cantidad de votantes que prefieren al candidato i sobre el j
+            let mut matriz_preferencias = vec![vec![0u64; n]; n];
+            for boleta in boletas.iter() {
+                let posiciones = candidatos
+                    .iter()
+                    .map(|c| boleta.iter().position(|b| b == c))
+                    .collect::<Vec<_>>();
+                for (i, fila) in matriz_preferencias.iter_mut().enumerate() {
+                    for (j, preferencias) in fila.iter_mut().enumerate() {
+                        if let (Some(pos_i), Some(pos_j)) = (posiciones[i], posiciones[j]) {
+                            if pos_i < pos_j {
+                                *preferencias =
+                                    preferencias.checked_add(1).ok_or(Error::Overflow)?;
+                            }
+                        }
+                    }
+                }
+            }
+
+            //matriz de caminos mas fuertes de schulze: arranca con las victorias directas y se mejora pasando por cada candidato intermedio
+            let mut matriz_caminos = vec![vec![0u64; n]; n];
+            for (i, fila) in matriz_caminos.iter_mut().enumerate() {
+                for (j, camino) in fila.iter_mut().enumerate() {
+                    if matriz_preferencias[i][j] > matriz_preferencias[j][i] {
+                        *camino = matriz_preferencias[i][j];
+                    }
+                }
+            }
+            for i in 0..n {
+                for j in 0..n {
+                    if i == j {
+                        continue;
+                    }
+                    for k in 0..n {
+                        if i != k && j != k {
+                            let pasando_por_i = matriz_caminos[j][i].min(matriz_caminos[i][k]);
+                            if pasando_por_i > matriz_caminos[j][k] {
+                                matriz_caminos[j][k] = pasando_por_i;
+                            }
+                        }
+                    }
+                }
+            }
+
+            //el ganador de condorcet le gana a todos los demas candidatos cara a cara
+            let ganador_condorcet = (0..n)
+                .find(|&i| {
+                    (0..n).all(|j| i == j || matriz_preferencias[i][j] > matriz_preferencias[j][i])
+                })
+                .map(|i| candidatos[i]);
+
+            //si no hay ganador de condorcet se usan los ganadores de schulze, los que no pierden ningun camino mas fuerte
+            let ganadores_schulze = if boletas.is_empty() {
+                Vec::new()
+            } else {
+                (0..n)
+                    .filter(|&i| {
+                        (0..n).all(|j| i == j || matriz_caminos[i][j] >= matriz_caminos[j][i])
+                    })
+                    .map(|i| candidatos[i])
+                    .collect()
+            };
+
+            Ok(ReporteCondorcet {
+                nro_eleccion: id,
+                candidatos,
+                matriz_preferencias,
+                matriz_caminos,
+                ganador_condorcet,
+                ganadores_schulze,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte con la matriz de preferencias cara a cara, la matriz de caminos mas fuertes de schulze y el ganador de condorcet si existe
+        pub fn generar_reporte_condorcet(&self, id: u64) -> Result<ReporteCondorcet, Error> {
+            self.generar_reporte_condorcet_priv(id)
+        }
+
+        fn generar_reporte_stv_priv(&self, id: u64) -> Result<ReporteStv, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            let bancas = match self.get_modo_eleccion(id)? {
+                ModoEleccion::Stv { bancas } => bancas,
+                _ => return Err(Error::ModoEleccionInvalido),
+            };
+
+            //traigo los candidatos y las boletas con las preferencias desde el contrato sistema_votacion
+            let mut en_carrera = self.get_candidatos(id)?.into_keys().collect::<Vec<_>>();
+            let boletas = self.get_boletas_preferenciales(id)?;
+
+            //cuota droop: la cantidad de boletas dividida la cantidad de bancas mas uno, mas un voto
+            let cuota = (boletas.len() as u64)
+                .checked_div(u64::from(bancas).checked_add(1).ok_or(Error::Overflow)?)
+                .ok_or(Error::Overflow)?
+                .checked_add(1)
+                .ok_or(Error::Overflow)?
+                .checked_mul(ESCALA_STV)
+                .ok_or(Error::Overflow)?;
+
+            //cada boleta arranca valiendo un voto entero y pierde valor cuando se transfiere un excedente
+            let mut pesos = vec![ESCALA_STV; boletas.len()];
+            let mut electos: Vec<AccountId> = Vec::new();
+            let mut rondas = Vec::new();
+            while electos.len() < bancas as usize && !en_carrera.is_empty() && !boletas.is_empty() {
+                //cada boleta suma su peso a su preferencia mas alta entre los candidatos que siguen en carrera
+                let mut conteo = en_carrera
+                    .iter()
+                    .map(|c| (*c, 0u64))
+                    .collect::<BTreeMap<_, _>>();
+                let mut preferidos = Vec::new();
+                for (boleta, peso) in boletas.iter().zip(pesos.iter()) {
+                    let preferido = boleta.iter().find(|c| en_carrera.contains(c)).copied();
+                    if let Some(votos) = preferido.and_then(|c| conteo.get_mut(&c)) {
+                        *votos = votos.checked_add(*peso).ok_or(Error::Overflow)?;
+                    }
+                    preferidos.push(preferido);
+                }
+                let mut conteo_ordenado = conteo.into_iter().collect::<Vec<_>>();
+                conteo_ordenado.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+
+                let (primero, votos_primero) = conteo_ordenado[0];
+                let bancas_libres = (bancas as usize).saturating_sub(electos.len());
+                let (electos_ronda, eliminado, transferido) = if en_carrera.len() <= bancas_libres {
+                    //si quedan tantos candidatos como bancas libres entran todos
+                    let restantes = conteo_ordenado.iter().map(|(c, _)| *c).collect();
+                    (restantes, None, 0)
+                } else if votos_primero >= cuota {
+                    //el excedente sobre la cuota se transfiere, las boletas del electo siguen valiendo solo la parte que sobro
+                    let excedente = votos_primero.checked_sub(cuota).ok_or(Error::Overflow)?;
+                    for (peso, preferido) in pesos.iter_mut().zip(preferidos.iter()) {
+                        if *preferido == Some(primero) {
+                            *peso = u128::from(*peso)
+                                .checked_mul(u128::from(excedente))
+                                .ok_or(Error::Overflow)?
+                                .checked_div(u128::from(votos_primero))
+                                .ok_or(Error::Overflow)? as u64;
+                        }
+                    }
+                    (vec![primero], None, excedente)
+                } else {
+                    //si nadie llega a la cuota se elimina al que tiene menos votos y sus boletas pasan enteras
+                    let (ultimo, votos_ultimo) = conteo_ordenado[conteo_ordenado.len() - 1];
+                    (Vec::new(), Some(ultimo), votos_ultimo)
+                };
+
+                en_carrera.retain(|c| !electos_ronda.contains(c) && Some(*c) != eliminado);
+                electos.extend(electos_ronda.iter().copied());
+                rondas.push(RondaStv {
+                    nro_ronda: rondas.len() as u64,
+                    conteo: conteo_ordenado,
+                    electos: electos_ronda,
+                    eliminado,
+                    transferido,
+                });
+            }
+
+            Ok(ReporteStv {
+                nro_eleccion: id,
+                bancas,
+                cuota,
+                rondas,
+                electos,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una elección stv con los electos y los votos transferidos en cada ronda
+        pub fn generar_reporte_stv(&self, id: u64) -> Result<ReporteStv, Error> {
+            self.generar_reporte_stv_priv(id)
+        }
+
+        fn generar_reporte_dhondt_priv(&self, id: u64) -> Result<ReporteDhondt, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            let (bancas, umbral) = match self.get_modo_eleccion(id)? {
+                ModoEleccion::Listas { bancas, umbral } => (bancas, umbral),
+                _ => return Err(Error::ModoEleccionInvalido),
+            };
+
+            //las listas que no llegan al umbral, un porcentaje del padron, quedan fuera del reparto
+            let padron = self.get_votantes(id)?.len() as u64;
+            let listas = self.get_listas(id)?;
+            let mut participantes = Vec::new();
+            let mut excluidas = Vec::new();
+            for lista in listas.iter() {
+                let supera_umbral = lista.votos().checked_mul(100).ok_or(Error::Overflow)?
+                    >= padron
+                        .checked_mul(u64::from(umbral))
+                        .ok_or(Error::Overflow)?;
+                let votos = if supera_umbral { lista.votos() } else { 0 };
+                participantes.push((votos, lista.candidatos().len()));
+                excluidas.push(!supera_umbral);
+            }
+            let bancas_por_lista = Self::repartir_dhondt(&participantes, bancas)?;
+
+            //cada lista se queda con sus primeros candidatos en el orden de la lista
+            let resultados = listas
+                .into_iter()
+                .zip(excluidas.into_iter().zip(bancas_por_lista))
+                .map(|(lista, (excluida, bancas))| ResultadoLista {
+                    nombre: lista.nombre().clone(),
+                    votos: lista.votos(),
+                    excluida,
+                    bancas,
+                    electos: lista
+                        .candidatos()
+                        .iter()
+                        .take(bancas as usize)
+                        .copied()
+                        .collect(),
+                })
+                .collect();
+
+            Ok(ReporteDhondt {
+                nro_eleccion: id,
+                bancas,
+                umbral,
+                listas: resultados,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una elección por listas con las bancas de cada lista repartidas por d'hondt y los candidatos electos
+        pub fn generar_reporte_dhondt(&self, id: u64) -> Result<ReporteDhondt, Error> {
+            self.generar_reporte_dhondt_priv(id)
+        }
+
+        fn generar_reporte_consulta_priv(&self, id: u64) -> Result<ReporteConsulta, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            if self.get_modo_eleccion(id)? != ModoEleccion::Consulta {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            let mut resultados = self.get_opciones(id)?;
+            resultados.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+
+            //si las dos opciones mas votadas empatan o nadie voto no hay opcion ganadora
+            let opcion_ganadora = match (resultados.first(), resultados.get(1)) {
+                (Some(primera), Some(segunda)) if primera.1 > segunda.1 => Some(primera.0.clone()),
+                _ => None,
+            };
+
+            Ok(ReporteConsulta {
+                nro_eleccion: id,
+                pregunta: self.get_cargo(id)?,
+                resultados,
+                opcion_ganadora,
+                votos_en_blanco: self.get_votos_en_blanco(id)?,
+                votos_nulos: self.get_votos_nulos(id)?,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una consulta popular con los votos de cada opcion ordenados de mayor a menor y la opcion ganadora
+        pub fn generar_reporte_consulta(&self, id: u64) -> Result<ReporteConsulta, Error> {
+            self.generar_reporte_consulta_priv(id)
+        }
+
+        fn generar_reporte_boleta_unica_priv(&self, id: u64) -> Result<ReporteBoletaUnica, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            if self.get_modo_eleccion(id)? != ModoEleccion::BoletaUnica {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            //cada categoria se cuenta por separado como una eleccion de mayoria
+            let categorias = self
+                .get_categorias(id)?
+                .into_iter()
+                .map(|categoria| {
+                    let mut resultados_ordenados: Vec<(AccountId, u64)> = categoria
+                        .candidatos()
+                        .iter()
+                        .map(|(id, votos)| (*id, *votos))
+                        .collect();
+                    resultados_ordenados.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+                    ResultadoCategoria {
+                        cargo: categoria.cargo().clone(),
+                        resultados_ordenados,
+                    }
+                })
+                .collect();
+
+            Ok(ReporteBoletaUnica {
+                nro_eleccion: id,
+                categorias,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una elección de boleta unica con los resultados de cada categoria ordenados de mayor a menor
+        pub fn generar_reporte_boleta_unica(&self, id: u64) -> Result<ReporteBoletaUnica, Error> {
+            self.generar_reporte_boleta_unica_priv(id)
+        }
+
+        fn generar_reporte_cuadratico_priv(&self, id: u64) -> Result<ReporteCuadratico, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            let creditos_por_votante = match self.get_modo_eleccion(id)? {
+                ModoEleccion::Cuadratico { creditos } => creditos,
+                _ => return Err(Error::ModoEleccionInvalido),
+            };
+
+            //junto los votos de cada candidato con los creditos que se gastaron en el y los ordeno por votos
+            let creditos_gastados = self.get_creditos_gastados(id)?;
+            let mut resultados: Vec<ResultadoCuadratico> = self
+                .get_candidatos(id)?
+                .into_iter()
+                .map(|(candidato, votos)| ResultadoCuadratico {
+                    candidato,
+                    votos,
+                    creditos: creditos_gastados.get(&candidato).copied().unwrap_or(0),
+                })
+                .collect();
+            resultados.sort_by_key(|resultado| core::cmp::Reverse(resultado.votos));
+
+            let mut creditos_totales: u64 = 0;
+            for resultado in resultados.iter() {
+                creditos_totales = creditos_totales
+                    .checked_add(resultado.creditos)
+                    .ok_or(Error::Overflow)?;
+            }
+
+            Ok(ReporteCuadratico {
+                nro_eleccion: id,
+                creditos_por_votante,
+                resultados,
+                creditos_totales,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una elección cuadratica con los votos y los creditos gastados en cada candidato
+        pub fn generar_reporte_cuadratico(&self, id: u64) -> Result<ReporteCuadratico, Error> {
+            self.generar_reporte_cuadratico_priv(id)
+        }
+
+        fn generar_reporte_acumulativo_priv(&self, id: u64) -> Result<ReporteAcumulativo, Error> {
+            let fecha_cierre = self.get_fecha_fin(id)?;
+            let fecha_inicio = self.get_fecha_inicio(id)?;
+            let fecha_actual = self.env().block_timestamp();
+
+            //verifica que la elección ya haya cerrado
+            if fecha_actual < fecha_cierre {
+                return Err(Error::EleccionAbierta);
+            }
+            //verifica que la elección ya haya iniciado
+            if fecha_actual < fecha_inicio {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            let (votos_por_votante, bancas) = match self.get_modo_eleccion(id)? {
+                ModoEleccion::Acumulativo {
+                    votos_por_votante,
+                    bancas,
+                    ..
+                } => (votos_por_votante, bancas),
+                _ => return Err(Error::ModoEleccionInvalido),
+            };
+
+            let mut resultados_ordenados = self.get_candidatos(id)?.into_iter().collect::<Vec<_>>();
+            resultados_ordenados.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+
+            //cuantos votantes le dieron al menos un voto a cada candidato, en el mismo orden que los resultados
+            let boletas = self.get_boletas_acumulativas(id)?;
+            let apoyos = resultados_ordenados
+                .iter()
+                .map(|(candidato, _)| {
+                    let votantes = boletas
+                        .values()
+                        .filter(|boleta| {
+                            boleta.iter().any(|(c, votos)| c == candidato && *votos > 0)
+                        })
+                        .count();
+                    (*candidato, votantes as u64)
+                })
+                .collect();
+
+            //entran los mas votados hasta cubrir las bancas
+            let electos = resultados_ordenados
+                .iter()
+                .take(bancas as usize)
+                .map(|(candidato, _)| *candidato)
+                .collect();
+
+            Ok(ReporteAcumulativo {
+                nro_eleccion: id,
+                bancas,
+                votos_por_votante,
+                resultados_ordenados,
+                apoyos,
+                electos,
+            })
+        }
+
+        #[ink(message)]
+        // Genera un reporte de una elección acumulativa con los votos de cada candidato, cuantos votantes lo apoyaron y los electos
+        pub fn generar_reporte_acumulativo(&self, id: u64) -> Result<ReporteAcumulativo, Error> {
+            self.generar_reporte_acumulativo_priv(id)
+        }
+
+        /// Reparte las bancas por d'hondt entre participantes dados como (votos, tope de bancas). Cada banca va al que tiene el mayor cociente votos / (bancas ya asignadas + 1); si empatan gana el de mas votos y despues el primero
+        fn repartir_dhondt(participantes: &[(u64, usize)], bancas: u32) -> Result<Vec<u32>, Error> {
+            let mut asignadas = vec![0u32; participantes.len()];
+            for _ in 0..bancas {
+                let mut mejor: Option<usize> = None;
+                for (i, (votos, tope)) in participantes.iter().enumerate() {
+                    if *votos == 0 || asignadas[i] as usize >= *tope {
+                        continue;
+                    }
+                    //comparo los cocientes multiplicando en cruz para no perder precision
+                    let es_mejor = match mejor {
+                        None => true,
+                        Some(m) => {
+                            let (votos_m, _) = participantes[m];
+                            let cociente_i = u128::from(*votos)
+                                .checked_mul(u128::from(asignadas[m]) + 1)
+                                .ok_or(Error::Overflow)?;
+                            let cociente_m = u128::from(votos_m)
+                                .checked_mul(u128::from(asignadas[i]) + 1)
+                                .ok_or(Error::Overflow)?;
+                            cociente_i > cociente_m
+                                || (cociente_i == cociente_m && *votos > votos_m)
+                        }
+                    };
+                    if es_mejor {
+                        mejor = Some(i);
+                    }
+                }
+                match mejor {
+                    Some(m) => asignadas[m] = asignadas[m].checked_add(1).ok_or(Error::Overflow)?,
+                    None => break,
+                }
+            }
+            Ok(asignadas)
+        }
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteRegistroVotantes {
+        nro_eleccion: u64,
+        votantes: Vec<Usuario>,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteParticipacion {
+        nro_eleccion: u64,
+        cantidad_votos_emitidos: u64,
+        porcentaje_participacion: u64,
+        votos_validos: u64,   // votantes que no anularon su voto
+        votos_en_blanco: u64, // suman el peso de cada votante
+        votos_nulos: u64,     // suman el peso de cada votante
+        votos_en_blanco_por_cabeza: u64,
+        votos_nulos_por_cabeza: u64,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteValidez {
+        nro_eleccion: u64,
+        cantidad_votos_emitidos: u64,
+        cantidad_votantes: u64,
+        quorum: u32,  // porcentaje minimo del padron que tenia que votar
+        valida: bool, // false si no se alcanzo el quorum
+        mayoria_requerida: MayoriaRequerida,
+        votos_validos: u64,         // votos a candidatos mas votos en blanco
+        ganador: Option<AccountId>, // None si la eleccion no es valida o nadie alcanzo la mayoria requerida
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteResultado {
+        nro_eleccion: u64,
+        resultados_ordenados: Vec<(AccountId, u64)>,
+        votos_por_cabeza: Vec<(AccountId, u64)>, // votantes que eligieron a cada candidato sin contar el peso de su voto
+        votos_escritos: Vec<(String, u64)>, // candidatos escritos por los votantes ordenados de mayor a menor
+        empate: Option<Empate>,             // solo si hay empate en el primer lugar
+        puntaje: Option<ResultadoPuntaje>,  // solo en elecciones de puntaje
+        votos_en_blanco: u64,
+        votos_nulos: u64,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Empate {
+        candidatos: Vec<AccountId>, // empatados en el primer lugar en el orden en que se registraron
+        politica: PoliticaDesempate, // regla con la que se resuelve el empate
+        ganador: Option<AccountId>, // None si se declara el empate o se resuelve con segunda vuelta
+        segunda_vuelta: Option<u64>, // id de la segunda vuelta si ya se creo
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ResultadoPuntaje {
+        promedios: Vec<(AccountId, u64)>, // puntaje promedio de cada candidato en centesimas de punto
+        segunda_vuelta: Option<SegundaVueltaPuntaje>,
+        ganador: Option<AccountId>,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct SegundaVueltaPuntaje {
+        finalistas: Vec<(AccountId, u64)>, // los dos candidatos con mas puntaje y la cantidad de boletas que prefirieron a cada uno
+        sin_preferencia: u64, // boletas que le dieron el mismo puntaje a los dos finalistas
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReportePreferencial {
+        nro_eleccion: u64,
+        rondas: Vec<RondaPreferencial>,
+        ganador: Option<AccountId>,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteBorda {
+        nro_eleccion: u64,
+        puntos_ordenados: Vec<(AccountId, u64)>,
+        empate: Option<Empate>, // candidatos empatados en el primer lugar y como se resolvio
+        ganador: Option<AccountId>,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteCondorcet {
+        nro_eleccion: u64,
+        candidatos: Vec<AccountId>, // orden de las filas y columnas de las matrices
+        matriz_preferencias: Vec<Vec<u64>>, // votantes que prefieren al candidato de la fila sobre el de la columna
+        matriz_caminos: Vec<Vec<u64>>, // fuerza del camino mas fuerte de schulze del candidato de la fila al de la columna
+        ganador_condorcet: Option<AccountId>,
+        ganadores_schulze: Vec<AccountId>, // mas de uno si hay empate
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteStv {
+        nro_eleccion: u64,
+        bancas: u32,
+        cuota: u64, // en millonesimas de voto, igual que los conteos de las rondas
+        rondas: Vec<RondaStv>,
+        electos: Vec<AccountId>, // en el orden en que fueron electos
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct RondaStv {
+        nro_ronda: u64,
+        conteo: Vec<(AccountId, u64)>, // votos en millonesimas de cada candidato en carrera ordenados de mayor a menor
+        electos: Vec<AccountId>,
+        eliminado: Option<AccountId>,
+        transferido: u64, // excedente del electo o votos del eliminado que pasan a la siguiente preferencia
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteDhondt {
+        nro_eleccion: u64,
+        bancas: u32,
+        umbral: u32, // porcentaje del padron que tiene que alcanzar una lista para entrar al reparto
+        listas: Vec<ResultadoLista>, // en el orden en que se registraron
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ResultadoLista {
+        nombre: String,
+        votos: u64,
+        excluida: bool, // no alcanzo el umbral
+        bancas: u32,
+        electos: Vec<AccountId>, // en el orden de la lista
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteConsulta {
+        nro_eleccion: u64,
+        pregunta: String,
+        resultados: Vec<(String, u64)>, // opciones con sus votos ordenadas de mayor a menor
+        opcion_ganadora: Option<String>, // None si hay empate en el primer lugar o no hubo votos
+        votos_en_blanco: u64,
+        votos_nulos: u64,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteBoletaUnica {
+        nro_eleccion: u64,
+        categorias: Vec<ResultadoCategoria>, // en el orden de la boleta
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ResultadoCategoria {
+        cargo: String,
+        resultados_ordenados: Vec<(AccountId, u64)>,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteCuadratico {
+        nro_eleccion: u64,
+        creditos_por_votante: u64,
+        resultados: Vec<ResultadoCuadratico>, // ordenados de mayor a menor por votos
+        creditos_totales: u64,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ResultadoCuadratico {
+        candidato: AccountId,
+        votos: u64,
+        creditos: u64, // creditos que gastaron todos los votantes en el candidato
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteAcumulativo {
+        nro_eleccion: u64,
+        bancas: u32,
+        votos_por_votante: u32,
+        resultados_ordenados: Vec<(AccountId, u64)>,
+        apoyos: Vec<(AccountId, u64)>, // votantes que le dieron al menos un voto a cada candidato
+        electos: Vec<AccountId>,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteDistritos {
+        nro_eleccion: u64,
+        regla: ReglaDistrital,
+        distritos: Vec<ResultadoDistrito>,
+        votos_totales: Vec<(AccountId, u64)>, // votos sumados de todos los distritos
+        electores_totales: Vec<(AccountId, u32)>, // electores ganados sumando todos los distritos
+        ganador: Option<AccountId>, // None si nadie junta mas de la mitad de los electores
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ResultadoDistrito {
+        nombre: String,
+        electores: u32,
+        resultados_ordenados: Vec<(AccountId, u64)>,
+        electores_por_candidato: Vec<(AccountId, u32)>, // vacio si el distrito empato con ganador se lleva todo
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ReporteVotoSecreto {
+        nro_eleccion: u64,
+        votos_revelados: u64,
+        compromisos_sin_revelar: Vec<AccountId>, // votantes que comprometieron su voto y no lo revelaron a tiempo
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct RondaPreferencial {
+        nro_ronda: u64,
+        conteo: Vec<(AccountId, u64)>, // votos de cada candidato en carrera ordenados de mayor a menor
+        eliminado: Option<AccountId>, // candidato eliminado al final de la ronda, None si en la ronda hubo ganador
+    }
+
+    #[cfg(test)]
+    mod tests {
+
+        use super::*;
+
+        #[ink::test]
+        fn test_generar_reporte_registro_votantes() {
+            let reporte = Reporte::new();
+
+            assert!(reporte.generar_reporte_registro_votantes(1).is_ok());
+            assert!(reporte.generar_reporte_registro_votantes(3).is_err());
+            let reporte_registro_votantes = ReporteRegistroVotantes {
                 nro_eleccion: 1,
                 votantes: vec![
                     Usuario::new(
@@ -303,11 +2056,206 @@ mod reporte {
                 nro_eleccion: 2,
                 cantidad_votos_emitidos: 2,
                 porcentaje_participacion: 100,
+                votos_validos: 1,
+                votos_en_blanco: 1,
+                votos_nulos: 1,
+                votos_en_blanco_por_cabeza: 1,
+                votos_nulos_por_cabeza: 1,
             };
             assert_eq!(
                 reporte.generar_reporte_participacion(2).unwrap(),
                 reporte_participacion
             );
+
+            // el voto nulo de un votante con peso 10 descuenta un solo votante de los votos validos
+            let reporte_participacion = reporte.generar_reporte_participacion(22).unwrap();
+            assert_eq!(reporte_participacion.votos_validos, 1);
+            assert_eq!(reporte_participacion.votos_nulos, 10);
+            assert_eq!(reporte_participacion.votos_nulos_por_cabeza, 1);
+        }
+        #[ink::test]
+        fn test_generar_reporte_validez() {
+            let reporte = Reporte::new();
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_validez(2).is_err());
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_validez(2).is_err());
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+
+            // los 6 votos a candidatos escritos son validos, 10 de 25 votos validos no es mayoria absoluta
+            let reporte_validez = ReporteValidez {
+                nro_eleccion: 2,
+                cantidad_votos_emitidos: 2,
+                cantidad_votantes: 2,
+                quorum: 50,
+                valida: true,
+                mayoria_requerida: MayoriaRequerida::Absoluta,
+                votos_validos: 25,
+                ganador: None,
+            };
+            assert_eq!(reporte.generar_reporte_validez(2).unwrap(), reporte_validez);
+
+            // con mayoria simple alcanza con ser el mas votado
+            assert_eq!(
+                reporte.generar_reporte_validez(1).unwrap().ganador,
+                Some(AccountId::from([0x1; 32]))
+            );
+
+            // y tampoco llega a los dos tercios
+            let reporte_validez = reporte.generar_reporte_validez(16).unwrap();
+            assert!(reporte_validez.valida);
+            assert_eq!(reporte_validez.ganador, None);
+
+            // con un empate en el primer lugar nadie alcanza la mayoria simple
+            assert_eq!(reporte.generar_reporte_validez(14).unwrap().ganador, None);
+
+            // en una eleccion por listas el mapa de candidatos no es el conteo
+            assert_eq!(
+                reporte.generar_reporte_validez(6),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            // 2 votos de un padron de 100 no llegan al quorum del 50%
+            let reporte_validez = reporte.generar_reporte_validez(21).unwrap();
+            assert!(!reporte_validez.valida);
+            assert_eq!(reporte_validez.ganador, None);
+        }
+        #[ink::test]
+        fn test_generar_reporte_distritos() {
+            let reporte = Reporte::new();
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_distritos(17).is_err());
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_distritos(17).is_err());
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+
+            // una eleccion sin distritos no tiene reporte por distritos
+            assert_eq!(
+                reporte.generar_reporte_distritos(2),
+                Err(Error::DistritoNoExiste)
+            );
+
+            // el 2 saca mas votos pero el 1 gana el colegio electoral, el Sur empata y no asigna electores
+            let reporte_distritos = ReporteDistritos {
+                nro_eleccion: 17,
+                regla: ReglaDistrital::GanadorSeLlevaTodo,
+                distritos: vec![
+                    ResultadoDistrito {
+                        nombre: "Norte".to_string(),
+                        electores: 8,
+                        resultados_ordenados: vec![
+                            (AccountId::from([0x1; 32]), 6),
+                            (AccountId::from([0x2; 32]), 4),
+                        ],
+                        electores_por_candidato: vec![(AccountId::from([0x1; 32]), 8)],
+                    },
+                    ResultadoDistrito {
+                        nombre: "Sur".to_string(),
+                        electores: 3,
+                        resultados_ordenados: vec![
+                            (AccountId::from([0x2; 32]), 5),
+                            (AccountId::from([0x3; 32]), 5),
+                        ],
+                        electores_por_candidato: vec![],
+                    },
+                    ResultadoDistrito {
+                        nombre: "Centro".to_string(),
+                        electores: 4,
+                        resultados_ordenados: vec![
+                            (AccountId::from([0x2; 32]), 7),
+                            (AccountId::from([0x1; 32]), 2),
+                            (AccountId::from([0x3; 32]), 1),
+                        ],
+                        electores_por_candidato: vec![(AccountId::from([0x2; 32]), 4)],
+                    },
+                ],
+                votos_totales: vec![
+                    (AccountId::from([0x2; 32]), 16),
+                    (AccountId::from([0x1; 32]), 8),
+                    (AccountId::from([0x3; 32]), 6),
+                ],
+                electores_totales: vec![
+                    (AccountId::from([0x1; 32]), 8),
+                    (AccountId::from([0x2; 32]), 4),
+                ],
+                ganador: Some(AccountId::from([0x1; 32])),
+            };
+            assert_eq!(
+                reporte.generar_reporte_distritos(17).unwrap(),
+                reporte_distritos
+            );
+
+            // repartiendo por d'hondt el 2 se queda con la mayoria de los electores
+            let reporte_distritos = reporte.generar_reporte_distritos(18).unwrap();
+            assert_eq!(
+                reporte_distritos.distritos[1].electores_por_candidato,
+                vec![
+                    (AccountId::from([0x2; 32]), 2),
+                    (AccountId::from([0x3; 32]), 1)
+                ]
+            );
+            assert_eq!(
+                reporte_distritos.electores_totales,
+                vec![
+                    (AccountId::from([0x2; 32]), 8),
+                    (AccountId::from([0x1; 32]), 6),
+                    (AccountId::from([0x3; 32]), 1),
+                ]
+            );
+            assert_eq!(reporte_distritos.ganador, Some(AccountId::from([0x2; 32])));
+        }
+        #[ink::test]
+        fn test_generar_reporte_voto_secreto() {
+            let reporte = Reporte::new();
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_voto_secreto(19).is_err());
+
+            // cerrada la eleccion los votos se siguen revelando y no hay resultados
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(150000);
+            assert_eq!(
+                reporte.generar_reporte_voto_secreto(19),
+                Err(Error::EleccionAbierta)
+            );
+            assert_eq!(
+                reporte.generar_reporte_resultado(19),
+                Err(Error::EleccionAbierta)
+            );
+            assert_eq!(
+                reporte.generar_reporte_participacion(19),
+                Err(Error::EleccionAbierta)
+            );
+            assert_eq!(
+                reporte.generar_reporte_validez(19),
+                Err(Error::EleccionAbierta)
+            );
+            assert_eq!(
+                reporte.generar_reporte_distritos(19),
+                Err(Error::EleccionAbierta)
+            );
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            assert!(reporte.generar_reporte_resultado(19).is_ok());
+            assert!(reporte.generar_reporte_participacion(19).is_ok());
+            assert!(reporte.generar_reporte_validez(19).is_ok());
+            assert_eq!(
+                reporte.generar_reporte_voto_secreto(2),
+                Err(Error::ModoEleccionInvalido)
+            );
+            let reporte_voto_secreto = ReporteVotoSecreto {
+                nro_eleccion: 19,
+                votos_revelados: 2,
+                compromisos_sin_revelar: vec![
+                    AccountId::from([0x4; 32]),
+                    AccountId::from([0x5; 32]),
+                ],
+            };
+            assert_eq!(
+                reporte.generar_reporte_voto_secreto(19).unwrap(),
+                reporte_voto_secreto
+            );
         }
         #[ink::test]
         fn test_generar_reporte_resultado() {
@@ -327,11 +2275,489 @@ mod reporte {
                     (AccountId::from([0x2; 32]), 5),
                     (AccountId::from([0x3; 32]), 3),
                 ],
+                votos_por_cabeza: vec![
+                    (AccountId::from([0x1; 32]), 2),
+                    (AccountId::from([0x2; 32]), 5),
+                    (AccountId::from([0x3; 32]), 3),
+                ],
+                votos_escritos: vec![("ana gomez".to_string(), 4), ("juan perez".to_string(), 2)],
+                empate: None,
+                puntaje: None,
+                votos_en_blanco: 1,
+                votos_nulos: 1,
             };
             assert_eq!(
                 reporte.generar_reporte_resultado(2).unwrap(),
                 reporte_resultado
             );
         }
+        #[ink::test]
+        fn test_generar_reporte_resultado_puntaje() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+
+            // el candidato 1 tiene mas puntaje total pero en la segunda vuelta star la mayoria prefiere al candidato 2
+            let reporte_resultado = ReporteResultado {
+                nro_eleccion: 3,
+                resultados_ordenados: vec![
+                    (AccountId::from([0x1; 32]), 10),
+                    (AccountId::from([0x2; 32]), 5),
+                    (AccountId::from([0x3; 32]), 3),
+                ],
+                votos_por_cabeza: vec![
+                    (AccountId::from([0x1; 32]), 2),
+                    (AccountId::from([0x2; 32]), 5),
+                    (AccountId::from([0x3; 32]), 3),
+                ],
+                votos_escritos: vec![("ana gomez".to_string(), 4), ("juan perez".to_string(), 2)],
+                empate: None,
+                puntaje: Some(ResultadoPuntaje {
+                    promedios: vec![
+                        (AccountId::from([0x1; 32]), 200),
+                        (AccountId::from([0x2; 32]), 100),
+                        (AccountId::from([0x3; 32]), 60),
+                    ],
+                    segunda_vuelta: Some(SegundaVueltaPuntaje {
+                        finalistas: vec![
+                            (AccountId::from([0x1; 32]), 2),
+                            (AccountId::from([0x2; 32]), 3),
+                        ],
+                        sin_preferencia: 0,
+                    }),
+                    ganador: Some(AccountId::from([0x2; 32])),
+                }),
+                votos_en_blanco: 1,
+                votos_nulos: 1,
+            };
+            assert_eq!(
+                reporte.generar_reporte_resultado(3).unwrap(),
+                reporte_resultado
+            );
+        }
+        #[ink::test]
+        fn test_generar_reporte_resultado_empate() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            let candidato1 = AccountId::from([0x1; 32]);
+            let candidato2 = AccountId::from([0x2; 32]);
+            let candidato3 = AccountId::from([0x3; 32]);
+
+            // sin politica se declara el empate y los empatados quedan en el orden en que se registraron
+            let reporte_resultado = reporte.generar_reporte_resultado(14).unwrap();
+            assert_eq!(
+                reporte_resultado.resultados_ordenados,
+                vec![(candidato2, 10), (candidato1, 10), (candidato3, 3)]
+            );
+            assert_eq!(
+                reporte_resultado.empate,
+                Some(Empate {
+                    candidatos: vec![candidato2, candidato1],
+                    politica: PoliticaDesempate::DeclararEmpate,
+                    ganador: None,
+                    segunda_vuelta: None,
+                })
+            );
+
+            // gana el que se registro primero
+            let reporte_resultado = reporte.generar_reporte_resultado(11).unwrap();
+            assert_eq!(reporte_resultado.resultados_ordenados[0], (candidato2, 10));
+            assert_eq!(reporte_resultado.empate.unwrap().ganador, Some(candidato2));
+
+            // el admin eligio al candidato 1 y queda primero
+            let reporte_resultado = reporte.generar_reporte_resultado(12).unwrap();
+            assert_eq!(
+                reporte_resultado.resultados_ordenados,
+                vec![(candidato1, 10), (candidato2, 10), (candidato3, 3)]
+            );
+            assert_eq!(
+                reporte_resultado.empate,
+                Some(Empate {
+                    candidatos: vec![candidato2, candidato1],
+                    politica: PoliticaDesempate::DecisionAdmin,
+                    ganador: Some(candidato1),
+                    segunda_vuelta: None,
+                })
+            );
+
+            // el empate se resuelve en la segunda vuelta
+            let empate = reporte
+                .generar_reporte_resultado(13)
+                .unwrap()
+                .empate
+                .unwrap();
+            assert_eq!(empate.ganador, None);
+            assert_eq!(empate.segunda_vuelta, Some(15));
+
+            // en preferencial empatar en primeras preferencias no es empatar la eleccion
+            assert_eq!(reporte.generar_reporte_resultado(20).unwrap().empate, None);
+        }
+        #[ink::test]
+        fn test_generar_reporte_preferencial() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_preferencial(1).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_preferencial(1).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            assert!(reporte.generar_reporte_preferencial(2).is_ok());
+
+            // en la primera ronda nadie tiene mayoria absoluta y se elimina al candidato 3, sus votos pasan al candidato 2
+            let reporte_preferencial = ReportePreferencial {
+                nro_eleccion: 2,
+                rondas: vec![
+                    RondaPreferencial {
+                        nro_ronda: 0,
+                        conteo: vec![
+                            (AccountId::from([0x1; 32]), 2),
+                            (AccountId::from([0x2; 32]), 2),
+                            (AccountId::from([0x3; 32]), 1),
+                        ],
+                        eliminado: Some(AccountId::from([0x3; 32])),
+                    },
+                    RondaPreferencial {
+                        nro_ronda: 1,
+                        conteo: vec![
+                            (AccountId::from([0x2; 32]), 3),
+                            (AccountId::from([0x1; 32]), 2),
+                        ],
+                        eliminado: None,
+                    },
+                ],
+                ganador: Some(AccountId::from([0x2; 32])),
+            };
+            assert_eq!(
+                reporte.generar_reporte_preferencial(2).unwrap(),
+                reporte_preferencial
+            );
+        }
+        #[ink::test]
+        fn test_generar_reporte_borda() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_borda(1).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_borda(1).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            let reporte_borda = ReporteBorda {
+                nro_eleccion: 2,
+                puntos_ordenados: vec![
+                    (AccountId::from([0x2; 32]), 7),
+                    (AccountId::from([0x1; 32]), 5),
+                    (AccountId::from([0x3; 32]), 3),
+                ],
+                empate: None,
+                ganador: Some(AccountId::from([0x2; 32])),
+            };
+            assert_eq!(reporte.generar_reporte_borda(2).unwrap(), reporte_borda);
+
+            // los candidatos 1 y 2 empatan en puntos y gana el 2 porque se registro primero
+            let reporte_borda = reporte.generar_reporte_borda(23).unwrap();
+            assert_eq!(
+                reporte_borda.empate.unwrap().candidatos,
+                vec![AccountId::from([0x2; 32]), AccountId::from([0x1; 32])]
+            );
+            assert_eq!(reporte_borda.ganador, Some(AccountId::from([0x2; 32])));
+        }
+        #[ink::test]
+        fn test_generar_reporte_condorcet() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_condorcet(1).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_condorcet(1).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+
+            // el candidato 2 le gana cara a cara al 1 (3 a 2) y al 3 (4 a 1)
+            let reporte_condorcet = ReporteCondorcet {
+                nro_eleccion: 2,
+                candidatos: vec![
+                    AccountId::from([0x1; 32]),
+                    AccountId::from([0x2; 32]),
+                    AccountId::from([0x3; 32]),
+                ],
+                matriz_preferencias: vec![vec![0, 2, 3], vec![3, 0, 4], vec![2, 1, 0]],
+                matriz_caminos: vec![vec![0, 0, 3], vec![3, 0, 4], vec![0, 0, 0]],
+                ganador_condorcet: Some(AccountId::from([0x2; 32])),
+                ganadores_schulze: vec![AccountId::from([0x2; 32])],
+            };
+            assert_eq!(
+                reporte.generar_reporte_condorcet(2).unwrap(),
+                reporte_condorcet
+            );
+
+            // sin ganador de condorcet gana el candidato 1 por schulze, su camino mas fuerte a los demas es mas fuerte que el de ellos hacia el
+            let reporte_condorcet = ReporteCondorcet {
+                nro_eleccion: 4,
+                candidatos: vec![
+                    AccountId::from([0x1; 32]),
+                    AccountId::from([0x2; 32]),
+                    AccountId::from([0x3; 32]),
+                ],
+                matriz_preferencias: vec![vec![0, 5, 3], vec![2, 0, 5], vec![4, 2, 0]],
+                matriz_caminos: vec![vec![0, 5, 5], vec![4, 0, 5], vec![4, 4, 0]],
+                ganador_condorcet: None,
+                ganadores_schulze: vec![AccountId::from([0x1; 32])],
+            };
+            assert_eq!(
+                reporte.generar_reporte_condorcet(4).unwrap(),
+                reporte_condorcet
+            );
+        }
+        #[ink::test]
+        fn test_generar_reporte_stv() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_stv(5).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_stv(5).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            // una eleccion que no es stv no tiene reporte stv
+            assert_eq!(
+                reporte.generar_reporte_stv(2),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            // 8 boletas y 2 bancas dan una cuota de 3 votos, el candidato 1 entra con 5 y sus 2 votos de excedente llevan al candidato 3 a la cuota
+            let reporte_stv = ReporteStv {
+                nro_eleccion: 5,
+                bancas: 2,
+                cuota: 3_000_000,
+                rondas: vec![
+                    RondaStv {
+                        nro_ronda: 0,
+                        conteo: vec![
+                            (AccountId::from([0x1; 32]), 5_000_000),
+                            (AccountId::from([0x2; 32]), 2_000_000),
+                            (AccountId::from([0x3; 32]), 1_000_000),
+                        ],
+                        electos: vec![AccountId::from([0x1; 32])],
+                        eliminado: None,
+                        transferido: 2_000_000,
+                    },
+                    RondaStv {
+                        nro_ronda: 1,
+                        conteo: vec![
+                            (AccountId::from([0x3; 32]), 3_000_000),
+                            (AccountId::from([0x2; 32]), 2_000_000),
+                        ],
+                        electos: vec![AccountId::from([0x3; 32])],
+                        eliminado: None,
+                        transferido: 0,
+                    },
+                ],
+                electos: vec![AccountId::from([0x1; 32]), AccountId::from([0x3; 32])],
+            };
+            assert_eq!(reporte.generar_reporte_stv(5).unwrap(), reporte_stv);
+        }
+        #[ink::test]
+        fn test_generar_reporte_dhondt() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_dhondt(6).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_dhondt(6).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            assert_eq!(
+                reporte.generar_reporte_dhondt(2),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            // la lista D no llega al 3% de un padron de 100, las 5 bancas se reparten 40, 30, 25, 20 (A) y 15 (B)
+            let reporte_dhondt = ReporteDhondt {
+                nro_eleccion: 6,
+                bancas: 5,
+                umbral: 3,
+                listas: vec![
+                    ResultadoLista {
+                        nombre: "A".to_string(),
+                        votos: 40,
+                        excluida: false,
+                        bancas: 2,
+                        electos: vec![AccountId::from([0x1; 32]), AccountId::from([0x2; 32])],
+                    },
+                    ResultadoLista {
+                        nombre: "B".to_string(),
+                        votos: 30,
+                        excluida: false,
+                        bancas: 2,
+                        electos: vec![AccountId::from([0x4; 32]), AccountId::from([0x5; 32])],
+                    },
+                    ResultadoLista {
+                        nombre: "C".to_string(),
+                        votos: 25,
+                        excluida: false,
+                        bancas: 1,
+                        electos: vec![AccountId::from([0x6; 32])],
+                    },
+                    ResultadoLista {
+                        nombre: "D".to_string(),
+                        votos: 2,
+                        excluida: true,
+                        bancas: 0,
+                        electos: Vec::new(),
+                    },
+                ],
+            };
+            assert_eq!(reporte.generar_reporte_dhondt(6).unwrap(), reporte_dhondt);
+        }
+        #[ink::test]
+        fn test_generar_reporte_consulta() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_consulta(7).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_consulta(7).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            assert_eq!(
+                reporte.generar_reporte_consulta(2),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            let reporte_consulta = ReporteConsulta {
+                nro_eleccion: 7,
+                pregunta: "¿Aprueba la reforma?".to_string(),
+                resultados: vec![
+                    ("No".to_string(), 9),
+                    ("Sí".to_string(), 7),
+                    ("Abstención".to_string(), 2),
+                ],
+                opcion_ganadora: Some("No".to_string()),
+                votos_en_blanco: 1,
+                votos_nulos: 1,
+            };
+            assert_eq!(
+                reporte.generar_reporte_consulta(7).unwrap(),
+                reporte_consulta
+            );
+        }
+        #[ink::test]
+        fn test_generar_reporte_boleta_unica() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_boleta_unica(8).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_boleta_unica(8).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            assert_eq!(
+                reporte.generar_reporte_boleta_unica(2),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            let reporte_boleta_unica = ReporteBoletaUnica {
+                nro_eleccion: 8,
+                categorias: vec![
+                    ResultadoCategoria {
+                        cargo: "Presidente".to_string(),
+                        resultados_ordenados: vec![
+                            (AccountId::from([0x2; 32]), 12),
+                            (AccountId::from([0x1; 32]), 10),
+                        ],
+                    },
+                    ResultadoCategoria {
+                        cargo: "Gobernador".to_string(),
+                        resultados_ordenados: vec![
+                            (AccountId::from([0x3; 32]), 15),
+                            (AccountId::from([0x4; 32]), 7),
+                        ],
+                    },
+                ],
+            };
+            assert_eq!(
+                reporte.generar_reporte_boleta_unica(8).unwrap(),
+                reporte_boleta_unica
+            );
+        }
+        #[ink::test]
+        fn test_generar_reporte_cuadratico() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_cuadratico(9).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_cuadratico(9).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            assert_eq!(
+                reporte.generar_reporte_cuadratico(2),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            let reporte_cuadratico = ReporteCuadratico {
+                nro_eleccion: 9,
+                creditos_por_votante: 100,
+                resultados: vec![
+                    ResultadoCuadratico {
+                        candidato: AccountId::from([0x1; 32]),
+                        votos: 10,
+                        creditos: 50,
+                    },
+                    ResultadoCuadratico {
+                        candidato: AccountId::from([0x2; 32]),
+                        votos: 5,
+                        creditos: 25,
+                    },
+                    ResultadoCuadratico {
+                        candidato: AccountId::from([0x3; 32]),
+                        votos: 3,
+                        creditos: 9,
+                    },
+                ],
+                creditos_totales: 84,
+            };
+            assert_eq!(
+                reporte.generar_reporte_cuadratico(9).unwrap(),
+                reporte_cuadratico
+            );
+        }
+        #[ink::test]
+        fn test_generar_reporte_acumulativo() {
+            let reporte = Reporte::new();
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(300);
+            assert!(reporte.generar_reporte_acumulativo(10).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(600);
+            assert!(reporte.generar_reporte_acumulativo(10).is_err());
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(10000000);
+            assert_eq!(
+                reporte.generar_reporte_acumulativo(2),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            let reporte_acumulativo = ReporteAcumulativo {
+                nro_eleccion: 10,
+                bancas: 2,
+                votos_por_votante: 3,
+                resultados_ordenados: vec![
+                    (AccountId::from([0x1; 32]), 10),
+                    (AccountId::from([0x2; 32]), 5),
+                    (AccountId::from([0x3; 32]), 3),
+                ],
+                apoyos: vec![
+                    (AccountId::from([0x1; 32]), 4),
+                    (AccountId::from([0x2; 32]), 2),
+                    (AccountId::from([0x3; 32]), 1),
+                ],
+                electos: vec![AccountId::from([0x1; 32]), AccountId::from([0x2; 32])],
+            };
+            assert_eq!(
+                reporte.generar_reporte_acumulativo(10).unwrap(),
+                reporte_acumulativo
+            );
+        }
     }
 }
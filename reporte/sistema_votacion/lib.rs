@@ -3,7 +3,18 @@
  */
 
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
+pub use self::sistema_votacion::calcular_compromiso;
+pub use self::sistema_votacion::calcular_recibo;
+pub use self::sistema_votacion::Categoria;
+pub use self::sistema_votacion::Distrito;
 pub use self::sistema_votacion::Error;
+pub use self::sistema_votacion::Genero;
+pub use self::sistema_votacion::Lista;
+pub use self::sistema_votacion::MayoriaRequerida;
+pub use self::sistema_votacion::ModoEleccion;
+pub use self::sistema_votacion::PoliticaDesempate;
+pub use self::sistema_votacion::ReglaDistrital;
+pub use self::sistema_votacion::ResultadoPrimeraVuelta;
 pub use self::sistema_votacion::RolUsuario;
 pub use self::sistema_votacion::SistemaVotacionRef;
 pub use self::sistema_votacion::Usuario;
@@ -84,17 +95,393 @@ mod sistema_votacion {
             let fecha_fin = fecha_f.to_timestamp()?;
 
             //creo la eleccion y la agrego al vector de elecciones
-            let eleccion = Eleccion {
-                id: self.elecciones.len() as u64,
-                cargo,
+            let eleccion =
+                Eleccion::new(self.elecciones.len() as u64, cargo, fecha_inicio, fecha_fin);
+            self.elecciones.push(eleccion);
+            Ok(())
+        }
+
+        #[ink(message)]
+        /// Funcion para crear una consulta popular con una pregunta y opciones fijas que no necesitan candidatos registrados, si no se ingresan opciones se usan Sí, No y Abstención. Solo el admin puede crear consultas
+        pub fn crear_consulta(
+            &mut self,
+            pregunta: String,
+            opciones: Vec<String>,
+            fecha_ini: Fecha,
+            fecha_f: Fecha,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.crear_consulta_priv(caller, pregunta, opciones, fecha_ini, fecha_f)
+        }
+
+        fn crear_consulta_priv(
+            &mut self,
+            caller: AccountId,
+            pregunta: String,
+            opciones: Vec<String>,
+            fecha_ini: Fecha,
+            fecha_f: Fecha,
+        ) -> Result<(), Error> {
+            let opciones = if opciones.is_empty() {
+                vec![
+                    String::from("Sí"),
+                    String::from("No"),
+                    String::from("Abstención"),
+                ]
+            } else {
+                opciones
+            };
+
+            // Verificar que haya al menos dos opciones y que no se repitan
+            if opciones.len() < 2
+                || opciones
+                    .iter()
+                    .enumerate()
+                    .any(|(i, opcion)| opciones[..i].contains(opcion))
+            {
+                return Err(Error::ConfiguracionInvalida);
+            }
+
+            // la consulta es una eleccion con la pregunta como cargo
+            self.crear_eleccion_priv(caller, pregunta, fecha_ini, fecha_f)?;
+            if let Some(consulta) = self.elecciones.last_mut() {
+                consulta.modo = ModoEleccion::Consulta;
+                consulta.opciones = opciones.into_iter().map(|o| (o, 0)).collect();
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        /// Funcion para evaluar el resultado de una eleccion de mayoria cerrada con las reglas argentinas: gana el que supera el 45% de los votos afirmativos o el que tiene al menos 40% y mas de 10 puntos de diferencia con el segundo. Si nadie gana o empatan en el primer lugar se crea la segunda vuelta entre los dos primeros, mas los que empaten con alguno de ellos, con los mismos votantes en las fechas ingresadas. Solo el admin puede evaluarla
+        pub fn evaluar_ballotage(
+            &mut self,
+            id_eleccion: u64,
+            fecha_ini: Fecha,
+            fecha_f: Fecha,
+        ) -> Result<ResultadoPrimeraVuelta, Error> {
+            let caller = self.env().caller();
+            self.evaluar_ballotage_priv(caller, id_eleccion, fecha_ini, fecha_f)
+        }
+
+        fn evaluar_ballotage_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            fecha_ini: Fecha,
+            fecha_f: Fecha,
+        ) -> Result<ResultadoPrimeraVuelta, Error> {
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            // Verificar que la elección ya haya cerrado
+            if !self.eleccion_cerrada(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            if eleccion.segunda_vuelta.is_some() {
+                return Err(Error::SegundaVueltaYaCreada);
+            }
+
+            // Los porcentajes se calculan sobre los votos afirmativos, sin contar blancos ni nulos
+            let mut resultados = eleccion
+                .candidatos
+                .iter()
+                .map(|(c, v)| (*c, *v))
+                .collect::<Vec<_>>();
+            resultados.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+            let votos_afirmativos = resultados
+                .iter()
+                .try_fold(0u64, |total, (_, votos)| total.checked_add(*votos))
+                .ok_or(Error::Overflow)?;
+            if votos_afirmativos == 0 {
+                return Err(Error::SinVotos);
+            }
+
+            let (primero, votos_primero) = resultados[0];
+            let votos_segundo = resultados.get(1).map_or(0, |(_, votos)| *votos);
+            let porcentaje_primero = votos_primero.checked_mul(100).ok_or(Error::Overflow)?;
+            let diferencia = (votos_primero - votos_segundo)
+                .checked_mul(100)
+                .ok_or(Error::Overflow)?;
+            let mas_de_45 =
+                porcentaje_primero > votos_afirmativos.checked_mul(45).ok_or(Error::Overflow)?;
+            let al_menos_40_y_10_de_diferencia = porcentaje_primero
+                >= votos_afirmativos.checked_mul(40).ok_or(Error::Overflow)?
+                && diferencia > votos_afirmativos.checked_mul(10).ok_or(Error::Overflow)?;
+            // Si empatan en el primer lugar nadie gana en primera vuelta
+            if votos_primero > votos_segundo && (mas_de_45 || al_menos_40_y_10_de_diferencia) {
+                return Ok(ResultadoPrimeraVuelta::Ganador(primero));
+            }
+
+            // Nadie gano en primera vuelta, la segunda vuelta tiene que arrancar despues de que cierre la primera
+            let fecha_inicio = fecha_ini.to_timestamp()?;
+            let fecha_fin = fecha_f.to_timestamp()?;
+            if fecha_inicio <= eleccion.fecha_fin || fecha_fin <= fecha_inicio {
+                return Err(Error::FechaInvalida);
+            }
+            // Pasan el primero y el segundo, si hay empatados en alguno de los dos lugares pasan todos los empatados
+            let finalistas = resultados
+                .iter()
+                .filter(|(_, votos)| *votos >= votos_segundo)
+                .map(|(candidato, _)| *candidato)
+                .collect();
+            let id_segunda_vuelta =
+                self.crear_segunda_vuelta(id_eleccion, finalistas, fecha_inicio, fecha_fin);
+            Ok(ResultadoPrimeraVuelta::SegundaVuelta(id_segunda_vuelta))
+        }
+
+        #[ink(message)]
+        /// Funcion para que el admin decida entre los candidatos empatados en el primer lugar de una eleccion cerrada cuya politica de desempate es la decision del admin
+        pub fn desempatar(
+            &mut self,
+            id_eleccion: u64,
+            id_candidato: AccountId,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.desempatar_priv(caller, id_eleccion, id_candidato)
+        }
+
+        fn desempatar_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            id_candidato: AccountId,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            // Verificar que la elección ya haya cerrado
+            if !self.eleccion_cerrada(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.politica_desempate != PoliticaDesempate::DecisionAdmin {
+                return Err(Error::PoliticaDesempateInvalida);
+            }
+            if eleccion.decision_desempate.is_some() {
+                return Err(Error::DesempateYaResuelto);
+            }
+
+            // Verificar que haya empate y que el elegido sea uno de los empatados
+            let empatados = eleccion.candidatos_empatados();
+            if empatados.len() < 2 {
+                return Err(Error::SinEmpate);
+            }
+            if !empatados.contains(&id_candidato) {
+                return Err(Error::CandidatoNoExiste);
+            }
+
+            eleccion.decision_desempate = Some(id_candidato);
+            Ok(())
+        }
+
+        #[ink(message)]
+        /// Funcion para crear la segunda vuelta entre los candidatos empatados en el primer lugar de una eleccion cerrada cuya politica de desempate es la segunda vuelta, devuelve el id de la segunda vuelta
+        pub fn crear_segunda_vuelta_por_empate(
+            &mut self,
+            id_eleccion: u64,
+            fecha_ini: Fecha,
+            fecha_f: Fecha,
+        ) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            self.crear_segunda_vuelta_por_empate_priv(caller, id_eleccion, fecha_ini, fecha_f)
+        }
+
+        fn crear_segunda_vuelta_por_empate_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            fecha_ini: Fecha,
+            fecha_f: Fecha,
+        ) -> Result<u64, Error> {
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            // Verificar que la elección ya haya cerrado
+            if !self.eleccion_cerrada(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if eleccion.politica_desempate != PoliticaDesempate::SegundaVuelta {
+                return Err(Error::PoliticaDesempateInvalida);
+            }
+            if !eleccion.modo.admite_desempate() {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            if eleccion.segunda_vuelta.is_some() {
+                return Err(Error::SegundaVueltaYaCreada);
+            }
+
+            let empatados = eleccion.candidatos_empatados();
+            if empatados.len() < 2 {
+                return Err(Error::SinEmpate);
+            }
+
+            // La segunda vuelta tiene que arrancar despues de que cierre la eleccion empatada
+            let fecha_inicio = fecha_ini.to_timestamp()?;
+            let fecha_fin = fecha_f.to_timestamp()?;
+            if fecha_inicio <= eleccion.fecha_fin || fecha_fin <= fecha_inicio {
+                return Err(Error::FechaInvalida);
+            }
+            Ok(self.crear_segunda_vuelta(id_eleccion, empatados, fecha_inicio, fecha_fin))
+        }
+
+        /// Funcion que crea una eleccion de mayoria entre los candidatos ingresados de una eleccion ya cerrada, con el mismo cargo y los mismos votantes registrados con sus pesos. Devuelve el id de la nueva eleccion
+        fn crear_segunda_vuelta(
+            &mut self,
+            id_eleccion: u64,
+            candidatos: Vec<AccountId>,
+            fecha_inicio: u64,
+            fecha_fin: u64,
+        ) -> u64 {
+            let id_segunda_vuelta = self.elecciones.len() as u64;
+            let original = &self.elecciones[id_eleccion as usize];
+            let mut segunda_vuelta = Eleccion::new(
+                id_segunda_vuelta,
+                original.cargo.clone(),
                 fecha_inicio,
                 fecha_fin,
-                candidatos: BTreeMap::new(),
-                candidatos_publicos: Vec::new(),
-                votantes: Vec::new(),
-                votantes_que_votaron: Vec::new(),
+            );
+            segunda_vuelta.votantes = original.votantes.clone();
+            segunda_vuelta.pesos = original.pesos.clone();
+            segunda_vuelta.candidatos_publicos = original
+                .candidatos_publicos
+                .iter()
+                .filter(|u| candidatos.contains(&u.id))
+                .cloned()
+                .collect();
+            segunda_vuelta.candidatos = candidatos.into_iter().map(|c| (c, 0)).collect();
+
+            self.elecciones[id_eleccion as usize].segunda_vuelta = Some(id_segunda_vuelta);
+            self.elecciones.push(segunda_vuelta);
+            id_segunda_vuelta
+        }
+
+        #[ink(message)]
+        /// Funcion para pasar la lista mas votada de cada agrupacion de unas primarias cerradas a la eleccion general. Quedan afuera las listas con menos del 1,5% de los votos validos (votos a listas y en blanco). Si la general es por listas la lista pasa entera con el nombre de la agrupacion, y si exige paridad y alguna ganadora no la cumple no pasa ninguna. Si es de mayoria pasa el primer candidato de la lista
+        pub fn promover_listas(&mut self, id_eleccion: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.promover_listas_priv(caller, id_eleccion)
+        }
+
+        fn promover_listas_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            // Verificar que las primarias ya hayan cerrado
+            if !self.eleccion_cerrada(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            let primarias = &self.elecciones[id_eleccion as usize];
+            let id_general = match primarias.modo {
+                ModoEleccion::Primarias { eleccion_general } => eleccion_general,
+                _ => return Err(Error::ModoEleccionInvalido),
             };
-            self.elecciones.push(eleccion);
+            if primarias.listas_promovidas {
+                return Err(Error::ListasYaPromovidas);
+            }
+
+            // Verificar que la eleccion general todavia no haya abierto
+            if !self.eleccion_no_abierta(id_general)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            // Los votos validos son los votos a listas mas los votos en blanco
+            let votos_validos = primarias
+                .listas
+                .iter()
+                .try_fold(primarias.votos_en_blanco, |total, l| {
+                    total.checked_add(l.votos)
+                })
+                .ok_or(Error::Overflow)?;
+            if votos_validos == 0 {
+                return Err(Error::SinVotos);
+            }
+
+            // La ganadora de cada agrupacion es su lista mas votada que supere el 1,5%, si empatan gana la que se registro primero
+            let mut ganadoras: Vec<&Lista> = Vec::new();
+            for lista in primarias.listas.iter() {
+                let supera_umbral = lista.votos.checked_mul(1000).ok_or(Error::Overflow)?
+                    >= votos_validos.checked_mul(15).ok_or(Error::Overflow)?;
+                if !supera_umbral || lista.votos == 0 {
+                    continue;
+                }
+                match ganadoras
+                    .iter_mut()
+                    .find(|g| g.agrupacion == lista.agrupacion)
+                {
+                    Some(ganadora) if lista.votos > ganadora.votos => *ganadora = lista,
+                    Some(_) => {}
+                    None => ganadoras.push(lista),
+                }
+            }
+            // Si la general exige paridad todas las ganadoras la tienen que cumplir, un candidato pudo cambiar su genero despues del cierre
+            if self.elecciones[id_general as usize].exige_paridad
+                && ganadoras
+                    .iter()
+                    .any(|g| !self.cumple_paridad(&g.candidatos))
+            {
+                return Err(Error::ListaSinParidad);
+            }
+            let ganadoras: Vec<Lista> = ganadoras.into_iter().cloned().collect();
+            let integrantes: Vec<Usuario> = primarias.candidatos_publicos.clone();
+
+            // Verificar que la general no tenga ya una lista con el nombre de alguna agrupacion
+            let general = &mut self.elecciones[id_general as usize];
+            if ganadoras
+                .iter()
+                .any(|g| general.listas.iter().any(|l| l.nombre == g.agrupacion))
+            {
+                return Err(Error::ConfiguracionInvalida);
+            }
+
+            for ganadora in ganadoras {
+                let promovidos: Vec<AccountId> = match general.modo {
+                    ModoEleccion::Listas { .. } => ganadora.candidatos.clone(),
+                    _ => ganadora.candidatos.iter().take(1).copied().collect(),
+                };
+                for id_candidato in promovidos.iter() {
+                    if !general
+                        .candidatos_publicos
+                        .iter()
+                        .any(|u| u.id == *id_candidato)
+                    {
+                        if let Some(usuario) = integrantes.iter().find(|u| u.id == *id_candidato) {
+                            general.candidatos_publicos.push(usuario.clone());
+                        }
+                    }
+                }
+                match general.modo {
+                    ModoEleccion::Listas { .. } => general.listas.push(Lista {
+                        agrupacion: ganadora.agrupacion.clone(),
+                        nombre: ganadora.agrupacion,
+                        candidatos: promovidos,
+                        votos: 0,
+                    }),
+                    _ => {
+                        for id_candidato in promovidos {
+                            general.candidatos.entry(id_candidato).or_insert(0);
+                        }
+                    }
+                }
+            }
+
+            self.elecciones[id_eleccion as usize].listas_promovidas = true;
             Ok(())
         }
 
@@ -189,787 +576,6106 @@ mod sistema_votacion {
         }
 
         #[ink(message)]
-        ///Funcion para mostrar a los usuarios los candidatos de una eleccion
-        pub fn mostrar_candidatos(&self, id_eleccion: u64) -> Result<Vec<Usuario>, Error> {
-            // Verificar que la elección exista
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
-            }
-            Ok(self.elecciones[id_eleccion as usize]
-                .candidatos_publicos
-                .clone())
-        }
-
-        //----------------------Funciones de registro---------------------------------------------------------
-
-        #[ink(message)]
-        /// Funcion para registrar un usuario en el sistema con los datos ingresados,distingue entre votante y candidato y verifica que no se registre el admin como usuario normal
-        pub fn registrar_usuario(
+        /// Funcion para cambiar el modo de votacion de una eleccion, solo el admin puede cambiarlo y solo antes de que la eleccion abra
+        pub fn set_modo_eleccion(
             &mut self,
-            nombre: String,
-            email: String,
-            rol: RolUsuario,
+            id_eleccion: u64,
+            modo: ModoEleccion,
         ) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.registrar_usuario_priv(caller, nombre, email, rol)
+            self.set_modo_eleccion_priv(caller, id_eleccion, modo)
         }
 
-        fn registrar_usuario_priv(
-            &mut self,
-            caller: AccountId,
-            nombre: String,
-            email: String,
-            rol: RolUsuario,
-        ) -> Result<(), Error> {
-            let usuario = Usuario {
-                id: caller,
-                nombre,
-                email,
-                rol,
-            };
-            // verifico que el usuario no este registrado y que no se registre el admin como usuario normal
-            if self.usuarios.iter().any(|u| u.id == usuario.id) || usuario.id == self.admin.id {
-                if usuario.id == self.admin.id {
-                    return Err(Error::AdminNoPuedeRegistrarse);
-                } else {
-                    return Err(Error::UsuarioYaRegistrado);
-                }
-            }
-            //agrego el usuario al vector de usuarios
-            self.usuarios.push(usuario);
-            Ok(())
-        }
-        #[ink(message)]
-        /// Funcion para registrar un votante en una eleccion con el id de la eleccion ingresado, solo puede ser llamada por un votante y no puede registrarse dos veces en la misma eleccion
-        pub fn registrar_votante_en_eleccion(&mut self, id_eleccion: u64) -> Result<(), Error> {
-            let caller = self.env().caller();
-            self.registrar_votante_en_eleccion_priv(caller, id_eleccion)
-        }
-        pub fn registrar_votante_en_eleccion_priv(
+        fn set_modo_eleccion_priv(
             &mut self,
             caller: AccountId,
             id_eleccion: u64,
+            modo: ModoEleccion,
         ) -> Result<(), Error> {
-            // Verificar que la elección no esté abierta
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            // Verificar que la elección no esté abierta, una vez que arranca la votacion no se puede cambiar el modo
             if !self.eleccion_no_abierta(id_eleccion)? {
                 return Err(Error::EleccionAbierta);
             }
 
-            // Verificar que la elección exista
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
+            // Las consultas se crean con sus opciones y no pueden pasar a otro modo ni crearse cambiando el modo
+            if modo == ModoEleccion::Consulta
+                || self.elecciones[id_eleccion as usize].modo == ModoEleccion::Consulta
+            {
+                return Err(Error::ModoEleccionInvalido);
             }
 
-            // Obtener el usuario completo del vector de usuarios
-            let usuario = self.usuarios.iter().find(|&u| u.id == caller).cloned();
+            // El voto secreto solo se puede revelar en mayoria, hay que sacarlo antes de cambiar el modo
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if modo != ModoEleccion::Mayoria && eleccion.fin_revelado.is_some() {
+                return Err(Error::ModoEleccionInvalido);
+            }
 
-            // Verificar que el usuario exista y sea un votante
-            let usuario = match usuario {
-                Some(u) if u.rol == RolUsuario::Votante => u,
-                _ => return Err(Error::UsuarioNoVotante),
-            };
+            // Los distritos solo se cuentan en mayoria, hay que crear la eleccion sin distritos para usar otro modo
+            if modo != ModoEleccion::Mayoria && !eleccion.distritos.is_empty() {
+                return Err(Error::ModoEleccionInvalido);
+            }
 
-            // Verificar que el usuario no esté registrado como votante en la elección
-            if self.elecciones[id_eleccion as usize]
-                .votantes
-                .iter()
-                .any(|v| v.id == usuario.id)
+            // Las reglas de validez solo se aplican en mayoria, hay que volver a las de por defecto antes de cambiar el modo
+            if modo != ModoEleccion::Mayoria
+                && (eleccion.quorum != 0 || eleccion.mayoria_requerida != MayoriaRequerida::Simple)
             {
-                return Err(Error::UsuarioYaRegistrado);
+                return Err(Error::ModoEleccionInvalido);
             }
 
-            // Registrar al usuario completo como votante en la elección
-            self.elecciones[id_eleccion as usize].votantes.push(usuario);
+            // Con una politica de desempate el nuevo modo tiene que admitirla
+            if eleccion.politica_desempate != PoliticaDesempate::DeclararEmpate
+                && !modo.admite_desempate()
+            {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que la configuracion del modo tenga sentido
+            match modo {
+                ModoEleccion::Puntaje { maximo: 0, .. } => {
+                    return Err(Error::ConfiguracionInvalida)
+                }
+                ModoEleccion::Stv { bancas } if bancas < 2 => {
+                    return Err(Error::ConfiguracionInvalida)
+                }
+                ModoEleccion::Listas { bancas, umbral } if bancas == 0 || umbral > 100 => {
+                    return Err(Error::ConfiguracionInvalida)
+                }
+                ModoEleccion::Cuadratico { creditos: 0 } => {
+                    return Err(Error::ConfiguracionInvalida)
+                }
+                ModoEleccion::Acumulativo {
+                    votos_por_votante,
+                    bancas,
+                    ..
+                } if votos_por_votante == 0 || bancas == 0 => {
+                    return Err(Error::ConfiguracionInvalida)
+                }
+                // La eleccion general tiene que existir, ser otra y ser de mayoria o por listas
+                ModoEleccion::Primarias { eleccion_general } => {
+                    match self.elecciones.get(eleccion_general as usize) {
+                        Some(general)
+                            if eleccion_general != id_eleccion
+                                && matches!(
+                                    general.modo,
+                                    ModoEleccion::Mayoria | ModoEleccion::Listas { .. }
+                                ) => {}
+                        _ => return Err(Error::ConfiguracionInvalida),
+                    }
+                }
+                _ => {}
+            }
+
+            self.elecciones[id_eleccion as usize].modo = modo;
             Ok(())
         }
 
         #[ink(message)]
-        /// Funcion para registrar un candidato en una eleccion con el id de la eleccion ingresado, solo puede ser llamada por un candidato y no puede registrarse dos veces en la misma eleccion
-        pub fn registrar_candidato_en_eleccion(&mut self, id_eleccion: u64) -> Result<(), Error> {
+        /// Funcion para permitir o no que los votantes escriban el nombre de un candidato que no esta registrado en una eleccion de mayoria, solo el admin puede cambiarlo y solo antes de que la eleccion abra
+        pub fn set_candidatos_escritos(
+            &mut self,
+            id_eleccion: u64,
+            permitir: bool,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.registrar_candidato_en_eleccion_priv(caller, id_eleccion)
+            self.set_candidatos_escritos_priv(caller, id_eleccion, permitir)
         }
-        fn registrar_candidato_en_eleccion_priv(
+
+        fn set_candidatos_escritos_priv(
             &mut self,
             caller: AccountId,
             id_eleccion: u64,
+            permitir: bool,
         ) -> Result<(), Error> {
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
             // Verificar que la elección no esté abierta
             if !self.eleccion_no_abierta(id_eleccion)? {
                 return Err(Error::EleccionAbierta);
             }
 
-            // Verificar que la elección exista
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
-            }
-
-            // Verificar que el usuario no esté registrado como candidato en la elección
-            if self.elecciones[id_eleccion as usize]
-                .candidatos
-                .contains_key(&caller)
-            {
-                return Err(Error::UsuarioYaRegistrado);
-            }
-
-            // Verificar que el usuario actual sea un candidato, si lo es, agregarlo a la lista de candidatos públicos de la elección
-            let mut usuario_es_candidato = false;
-            for usuario in self.usuarios.iter() {
-                if usuario.id == caller && usuario.rol == RolUsuario::Candidato {
-                    usuario_es_candidato = true;
-
-                    self.elecciones[id_eleccion as usize]
-                        .candidatos_publicos
-                        .push(usuario.clone());
-                    break;
-                }
-            }
-
-            // Si el usuario no es un candidato, lanzar un error
-            if !usuario_es_candidato {
-                return Err(Error::UsuarioNoCandidato);
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
             }
 
-            // Registrar al usuario como candidato en la elección
-            self.elecciones[id_eleccion as usize]
-                .candidatos
-                .insert(caller, 0);
+            eleccion.permite_candidatos_escritos = permitir;
             Ok(())
         }
 
-        //----------------------Funciones de votacion---------------------------------------------------------
         #[ink(message)]
-        /// Funcion para votar en una eleccion con el id de la eleccion y el id del candidato ingresados, solo puede votar un votante y no puede votar dos veces en la misma eleccion
-        pub fn votar(&mut self, id_eleccion: u64, id_candidato: AccountId) -> Result<(), Error> {
+        /// Funcion para exigir la paridad de genero en las listas de una eleccion por listas o de unas primarias, solo el admin puede cambiarlo y solo antes de que la eleccion abra. Al activarla se validan las listas ya registradas, tambien las de las primarias de esta eleccion, y las listas de esas primarias se validan al registrarse
+        pub fn set_paridad(&mut self, id_eleccion: u64, exigir: bool) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.votar_priv(caller, id_eleccion, id_candidato)
+            self.set_paridad_priv(caller, id_eleccion, exigir)
         }
-        fn votar_priv(
+
+        fn set_paridad_priv(
             &mut self,
             caller: AccountId,
             id_eleccion: u64,
-            id_candidato: AccountId,
+            exigir: bool,
         ) -> Result<(), Error> {
-            // Verificar que la elección esté activa
-            if !self.eleccion_activa(id_eleccion)? {
-                return Err(Error::EleccionNoActiva);
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
             }
 
-            //buscar al votante en el vector de usuarios y verificar que sea un votante
-            let votante = self.usuarios.iter().find(|&u| u.id == caller).cloned();
-            let votante = match votante {
-                Some(u) if u.rol == RolUsuario::Votante => u,
-                _ => return Err(Error::UsuarioNoVotante),
-            };
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
 
-            // si es un votante, votar en la eleccion
-            self.elecciones[id_eleccion as usize].votar_en_eleccion(id_candidato, votante)?;
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if !matches!(
+                eleccion.modo,
+                ModoEleccion::Listas { .. } | ModoEleccion::Primarias { .. }
+            ) {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            // Se validan las listas de la eleccion y las de sus primarias que todavia no se promovieron
+            if exigir
+                && self
+                    .elecciones
+                    .iter()
+                    .filter(|e| {
+                        e.id == id_eleccion
+                            || (!e.listas_promovidas
+                                && e.modo
+                                    == ModoEleccion::Primarias {
+                                        eleccion_general: id_eleccion,
+                                    })
+                    })
+                    .flat_map(|e| e.listas.iter())
+                    .any(|l| !self.cumple_paridad(&l.candidatos))
+            {
+                return Err(Error::ListaSinParidad);
+            }
 
+            self.elecciones[id_eleccion as usize].exige_paridad = exigir;
             Ok(())
         }
 
-        //----------------------Funciones para el reporte---------------------------------------------------------
-        // en estas funciones tengo que verificar que el que llama sea el contrato de reporte y no otro contrato o usuario
-
         #[ink(message)]
-        /// Funcion para settear el id del contrato de reporte en el sistema de votacion, solo puede ser setteado por el admin. Esto sirve para que el contrato de reporte pueda acceder a los datos del sistema de votacion
-        pub fn set_id_contrato(&mut self, id: AccountId) -> Result<(), Error> {
+        /// Funcion para agregar un distrito con su cantidad de electores a una eleccion de mayoria, solo el admin puede agregar distritos y solo antes de que la eleccion abra. Si la eleccion tiene distritos cada votante tiene que tener uno asignado para votar
+        pub fn agregar_distrito(
+            &mut self,
+            id_eleccion: u64,
+            nombre: String,
+            electores: u32,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
+            self.agregar_distrito_priv(caller, id_eleccion, nombre, electores)
+        }
+
+        fn agregar_distrito_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            nombre: String,
+            electores: u32,
+        ) -> Result<(), Error> {
             if self.admin.id != caller {
                 return Err(Error::PermisoDenegado);
             }
 
-            self.id_contrato_reporte = id;
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el distrito tenga electores y que no haya otro con el mismo nombre
+            if electores == 0 || eleccion.distritos.iter().any(|d| d.nombre == nombre) {
+                return Err(Error::ConfiguracionInvalida);
+            }
+
+            eleccion.distritos.push(Distrito {
+                nombre,
+                electores,
+                candidatos: BTreeMap::new(),
+            });
             Ok(())
         }
 
         #[ink(message)]
-        /// Funcion para obtener el id del contrato de reporte solo puede ser llamada por el contrato de reporte
-        pub fn get_tamanio_elecciones(&self) -> Result<u64, Error> {
+        /// Funcion para asignar un votante registrado en una eleccion a uno de sus distritos, solo el admin puede asignarlo y solo antes de que la eleccion abra
+        pub fn asignar_distrito(
+            &mut self,
+            id_eleccion: u64,
+            id_votante: AccountId,
+            nro_distrito: u64,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            if self.id_contrato_reporte != caller {
-                return Err(Error::PermisoDenegado);
-            }
-            Ok(self.elecciones.len() as u64)
+            self.asignar_distrito_priv(caller, id_eleccion, id_votante, nro_distrito)
         }
 
-        fn get_candidatos_priv(
-            &self,
-            id_eleccion: u64,
+        fn asignar_distrito_priv(
+            &mut self,
             caller: AccountId,
-        ) -> Result<BTreeMap<AccountId, u64>, Error> {
-            if self.id_contrato_reporte != caller {
+            id_eleccion: u64,
+            id_votante: AccountId,
+            nro_distrito: u64,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
                 return Err(Error::PermisoDenegado);
             }
 
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
             }
 
-            Ok(self.elecciones[id_eleccion as usize].candidatos.clone())
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if nro_distrito as usize >= eleccion.distritos.len() {
+                return Err(Error::DistritoNoExiste);
+            }
+            if !eleccion.votantes.iter().any(|v| v.id == id_votante) {
+                return Err(Error::UsuarioNoVotante);
+            }
+
+            eleccion.distrito_votantes.insert(id_votante, nro_distrito);
+            Ok(())
         }
 
         #[ink(message)]
-        ///Funcion para obtener los candidatos con sus votos de una eleccion solo puede ser llamada por el contrato de reporte
-        pub fn get_candidatos(&self, id_eleccion: u64) -> Result<BTreeMap<AccountId, u64>, Error> {
+        /// Funcion para elegir como se reparten los electores de cada distrito de una eleccion, solo el admin puede cambiarlo y solo antes de que la eleccion abra. Por defecto el ganador del distrito se lleva todos sus electores
+        pub fn set_regla_distrital(
+            &mut self,
+            id_eleccion: u64,
+            regla: ReglaDistrital,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.get_candidatos_priv(id_eleccion, caller)
+            self.set_regla_distrital_priv(caller, id_eleccion, regla)
         }
 
-        fn get_votantes_priv(
-            &self,
-            id_eleccion: u64,
+        fn set_regla_distrital_priv(
+            &mut self,
             caller: AccountId,
-        ) -> Result<Vec<Usuario>, Error> {
-            if self.id_contrato_reporte != caller {
+            id_eleccion: u64,
+            regla: ReglaDistrital,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
                 return Err(Error::PermisoDenegado);
             }
 
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
             }
 
-            Ok(self.elecciones[id_eleccion as usize].votantes.clone())
+            self.elecciones[id_eleccion as usize].regla_distrital = regla;
+            Ok(())
         }
 
         #[ink(message)]
-        /// Funcion para obtener los votantes registrados de una eleccion solo puede ser llamada por el contrato de reporte
-        pub fn get_votantes(&self, id_eleccion: u64) -> Result<Vec<Usuario>, Error> {
+        /// Funcion para que una eleccion de mayoria tenga voto secreto, solo el admin puede cambiarlo y solo antes de que la eleccion abra. Mientras la eleccion esta abierta los votantes solo comprometen su voto a un candidato y lo revelan entre el cierre y fin_revelado, no se puede votar en blanco ni nulo. None vuelve al voto comun
+        pub fn set_voto_secreto(
+            &mut self,
+            id_eleccion: u64,
+            fin_revelado: Option<Fecha>,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.get_votantes_priv(id_eleccion, caller)
+            self.set_voto_secreto_priv(caller, id_eleccion, fin_revelado)
         }
 
-        fn get_votantes_que_votaron_priv(
-            &self,
-            id_eleccion: u64,
+        fn set_voto_secreto_priv(
+            &mut self,
             caller: AccountId,
-        ) -> Result<Vec<Usuario>, Error> {
-            if self.id_contrato_reporte != caller {
+            id_eleccion: u64,
+            fin_revelado: Option<Fecha>,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
                 return Err(Error::PermisoDenegado);
             }
 
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
             }
-            Ok(self.elecciones[id_eleccion as usize]
-                .votantes_que_votaron
-                .clone())
-        }
 
-        #[ink(message)]
-        /// Funcion para obtener los votantes que votaron de una eleccion solo puede ser llamada por el contrato de reporte
-        pub fn get_votantes_que_votaron(&self, id_eleccion: u64) -> Result<Vec<Usuario>, Error> {
-            let caller = self.env().caller();
-            self.get_votantes_que_votaron_priv(id_eleccion, caller)
-        }
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
+            }
 
-        fn get_usuarios_priv(&self, caller: AccountId) -> Result<Vec<Usuario>, Error> {
-            if self.id_contrato_reporte != caller {
-                return Err(Error::PermisoDenegado);
+            // El revelado tiene que terminar despues del cierre de la eleccion
+            let fin_revelado = match fin_revelado {
+                Some(fecha) => Some(fecha.to_timestamp()?),
+                None => None,
+            };
+            if fin_revelado.is_some_and(|fin| fin <= eleccion.fecha_fin) {
+                return Err(Error::FechaInvalida);
             }
-            Ok(self.usuarios.clone())
-        }
 
-        #[ink(message)]
-        /// Funcion para obtener los usuarios registrados en el sistema, pueden ser votantes o candidatos. Solo puede ser llamada por el contrato de reporte
-        pub fn get_usuarios(&self) -> Result<Vec<Usuario>, Error> {
-            let caller = self.env().caller();
-            self.get_usuarios_priv(caller)
+            eleccion.fin_revelado = fin_revelado;
+            Ok(())
         }
 
-        fn get_fecha_inicio_priv(&self, id_eleccion: u64, caller: AccountId) -> Result<u64, Error> {
-            if self.id_contrato_reporte != caller {
-                return Err(Error::PermisoDenegado);
-            }
+        /// Funcion que devuelve si las listas de una eleccion tienen que cumplir la paridad, porque la exige la eleccion o porque son primarias de una eleccion general que la exige
+        fn exige_paridad(&self, eleccion: &Eleccion) -> bool {
+            eleccion.exige_paridad
+                || match eleccion.modo {
+                    ModoEleccion::Primarias { eleccion_general } => self
+                        .elecciones
+                        .get(eleccion_general as usize)
+                        .is_some_and(|general| general.exige_paridad),
+                    _ => false,
+                }
+        }
 
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
+        /// Funcion que verifica la paridad de una lista, todos los candidatos tienen que haber declarado su genero. Las mujeres y los varones tienen que alternarse en el orden de la lista, los candidatos no binarios pueden ir en cualquier lugar y no cortan la alternancia, por eso mujer, no binario, mujer no cumple
+        fn cumple_paridad(&self, candidatos: &[AccountId]) -> bool {
+            // Ultimo genero binario de la lista, los no binarios se saltean
+            let mut anterior: Option<&Genero> = None;
+            for id_candidato in candidatos.iter() {
+                let genero = match self
+                    .usuarios
+                    .iter()
+                    .find(|u| u.id == *id_candidato)
+                    .and_then(|u| u.genero.as_ref())
+                {
+                    Some(genero) => genero,
+                    None => return false,
+                };
+                if *genero == Genero::NoBinario {
+                    continue;
+                }
+                if anterior == Some(genero) {
+                    return false;
+                }
+                anterior = Some(genero);
             }
-
-            Ok(self.elecciones[id_eleccion as usize].fecha_inicio)
+            true
         }
 
         #[ink(message)]
-        /// Funcion para obtener la fecha de inicio de una eleccion solo puede ser llamada por el contrato de reporte
-        pub fn get_fecha_inicio(&self, id_eleccion: u64) -> Result<u64, Error> {
+        /// Funcion para fijar el quorum (porcentaje minimo del padron que tiene que votar) y la mayoria que necesita el ganador de una eleccion de mayoria, solo el admin puede cambiarlos y solo antes de que la eleccion abra. Por defecto no hay quorum y alcanza la mayoria simple
+        pub fn set_reglas_validez(
+            &mut self,
+            id_eleccion: u64,
+            quorum: u32,
+            mayoria_requerida: MayoriaRequerida,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.get_fecha_inicio_priv(id_eleccion, caller)
+            self.set_reglas_validez_priv(caller, id_eleccion, quorum, mayoria_requerida)
         }
 
-        fn get_fecha_fin_priv(&self, id_eleccion: u64, caller: AccountId) -> Result<u64, Error> {
-            if self.id_contrato_reporte != caller {
+        fn set_reglas_validez_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            quorum: u32,
+            mayoria_requerida: MayoriaRequerida,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
                 return Err(Error::PermisoDenegado);
             }
 
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
             }
 
-            Ok(self.elecciones[id_eleccion as usize].fecha_fin)
+            // El quorum es un porcentaje del padron
+            if quorum > 100 {
+                return Err(Error::ConfiguracionInvalida);
+            }
+
+            // El reporte de validez solo se calcula en mayoria
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            eleccion.quorum = quorum;
+            eleccion.mayoria_requerida = mayoria_requerida;
+            Ok(())
         }
 
         #[ink(message)]
-        /// Funcion para obtener la fecha de fin de una eleccion solo puede ser llamada por el contrato de reporte
-        pub fn get_fecha_fin(&self, id_eleccion: u64) -> Result<u64, Error> {
+        /// Funcion para elegir como se resuelve un empate en el primer lugar de una eleccion, solo el admin puede cambiarla y solo antes de que la eleccion abra. Por defecto se declara el empate
+        pub fn set_politica_desempate(
+            &mut self,
+            id_eleccion: u64,
+            politica: PoliticaDesempate,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.get_fecha_fin_priv(id_eleccion, caller)
+            self.set_politica_desempate_priv(caller, id_eleccion, politica)
         }
 
-        fn get_cargo_priv(&self, id_eleccion: u64, caller: AccountId) -> Result<String, Error> {
-            if self.id_contrato_reporte != caller {
+        fn set_politica_desempate_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            politica: PoliticaDesempate,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
                 return Err(Error::PermisoDenegado);
             }
 
-            if id_eleccion as usize >= self.elecciones.len() {
-                return Err(Error::EleccionNoExiste);
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
             }
 
-            Ok(self.elecciones[id_eleccion as usize].cargo.clone())
+            // Declarar el empate se puede en cualquier modo, el resto de las politicas solo si el conteo es el resultado final
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if politica != PoliticaDesempate::DeclararEmpate && !eleccion.modo.admite_desempate() {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            eleccion.politica_desempate = politica;
+            Ok(())
         }
 
         #[ink(message)]
-        /// Funcion para obtener el cargo de una eleccion solo puede ser llamada por el contrato de reporte
-        pub fn get_cargo(&self, id_eleccion: u64) -> Result<String, Error> {
+        ///Funcion para mostrar a los usuarios los candidatos de una eleccion
+        pub fn mostrar_candidatos(&self, id_eleccion: u64) -> Result<Vec<Usuario>, Error> {
+            // Verificar que la elección exista
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+            Ok(self.elecciones[id_eleccion as usize]
+                .candidatos_publicos
+                .clone())
+        }
+
+        //----------------------Funciones de registro---------------------------------------------------------
+
+        #[ink(message)]
+        /// Funcion para registrar un usuario en el sistema con los datos ingresados,distingue entre votante y candidato y verifica que no se registre el admin como usuario normal
+        pub fn registrar_usuario(
+            &mut self,
+            nombre: String,
+            email: String,
+            rol: RolUsuario,
+        ) -> Result<(), Error> {
             let caller = self.env().caller();
-            self.get_cargo_priv(id_eleccion, caller)
+            self.registrar_usuario_priv(caller, nombre, email, rol)
         }
-    }
-    //----------------------Funciones de eleccion---------------------------------------------------------
-    impl Eleccion {
-        fn votar_en_eleccion(
+
+        fn registrar_usuario_priv(
             &mut self,
-            id_candidato: AccountId,
-            votante: Usuario,
+            caller: AccountId,
+            nombre: String,
+            email: String,
+            rol: RolUsuario,
         ) -> Result<(), Error> {
-            // Incrementar el conteo de votos del candidato
-            // Verificar que el candidato exista
-            if let Some(votos) = self.candidatos.get_mut(&id_candidato) {
-                // Verificar que el votante no haya votado ya
-                if self.votantes_que_votaron.contains(&votante) {
+            let usuario = Usuario {
+                id: caller,
+                nombre,
+                email,
+                rol,
+                genero: None,
+            };
+            // verifico que el usuario no este registrado y que no se registre el admin como usuario normal
+            if self.usuarios.iter().any(|u| u.id == usuario.id) || usuario.id == self.admin.id {
+                if usuario.id == self.admin.id {
+                    return Err(Error::AdminNoPuedeRegistrarse);
+                } else {
                     return Err(Error::UsuarioYaRegistrado);
                 }
+            }
+            //agrego el usuario al vector de usuarios
+            self.usuarios.push(usuario);
+            Ok(())
+        }
+        #[ink(message)]
+        /// Funcion para que un usuario registrado declare su genero, es necesario para integrar listas en elecciones con paridad. Mientras integre una lista de una eleccion con paridad que no cerro no lo puede cambiar
+        pub fn set_genero(&mut self, genero: Genero) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.set_genero_priv(caller, genero)
+        }
 
-                // Intentar incrementar el conteo de votos, manejando el posible overflow
-                *votos = votos.checked_add(1).ok_or(Error::Overflow)?;
-
-                // Agregar al votante a la lista de votantes que votaron
-                self.votantes_que_votaron.push(votante);
-            } else {
-                return Err(Error::CandidatoNoExiste);
+        fn set_genero_priv(&mut self, caller: AccountId, genero: Genero) -> Result<(), Error> {
+            // Si el usuario esta en una lista de una eleccion con paridad que no cerro su genero no puede cambiar, la paridad se valido con ese genero
+            let fecha_actual = self.env().block_timestamp();
+            if self.elecciones.iter().any(|e| {
+                self.exige_paridad(e)
+                    && fecha_actual <= e.fecha_fin
+                    && e.listas.iter().any(|l| l.candidatos.contains(&caller))
+            }) {
+                return Err(Error::GeneroBloqueado);
             }
+
+            let usuario = self
+                .usuarios
+                .iter_mut()
+                .find(|u| u.id == caller)
+                .ok_or(Error::UsuarioNoRegistrado)?;
+            usuario.genero = Some(genero);
             Ok(())
         }
-    }
 
-    //----------------------Funciones de fecha---------------------------------------------------------
+        #[ink(message)]
+        /// Funcion para registrar un votante en una eleccion con el id de la eleccion ingresado, solo puede ser llamada por un votante y no puede registrarse dos veces en la misma eleccion
+        pub fn registrar_votante_en_eleccion(&mut self, id_eleccion: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.registrar_votante_en_eleccion_priv(caller, id_eleccion)
+        }
+        pub fn registrar_votante_en_eleccion_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+        ) -> Result<(), Error> {
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
 
-    impl Fecha {
-        pub fn to_timestamp(&self) -> Result<u64, Error> {
-            let date = NaiveDate::from_ymd_opt(self.anio, self.mes, self.dias)
-                .ok_or(Error::FechaInvalida)?; // Manejo del error para una fecha inválida
+            // Verificar que la elección exista
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
 
-            // Arranca a las 00:00:00 del día
-            let datetime = date.and_hms_opt(0, 0, 0).ok_or(Error::FechaInvalida)?;
+            // Obtener el usuario completo del vector de usuarios
+            let usuario = self.usuarios.iter().find(|&u| u.id == caller).cloned();
 
-            let timestamp_secs = datetime.and_utc().timestamp() as u64;
+            // Verificar que el usuario exista y sea un votante
+            let usuario = match usuario {
+                Some(u) if u.rol == RolUsuario::Votante => u,
+                _ => return Err(Error::UsuarioNoVotante),
+            };
 
-            // Pasar a milisegundos, manejando posible overflow
-            timestamp_secs.checked_mul(1000).ok_or(Error::Overflow)
-        }
-    }
-    //----------------------Structs de admin y eleccion---------------------------------------------------------
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    struct Admin {
-        id: AccountId,
-        nombre: String,
-        email: String,
-        password: String,
-    }
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    pub struct Eleccion {
-        id: u64,
-        cargo: String,
-        fecha_inicio: u64,
-        fecha_fin: u64,
-        candidatos: BTreeMap<AccountId, u64>, // btree para contar los votos de los candidatos eficientemente
-        candidatos_publicos: Vec<Usuario>, // vector para mostrar los candidatos de una eleccion a los usuarios
-        votantes: Vec<Usuario>,            // vector de votantes registrados en la eleccion
-        votantes_que_votaron: Vec<Usuario>, // vector de votantes que votaron en la eleccion
-    }
+            // Verificar que el usuario no esté registrado como votante en la elección
+            if self.elecciones[id_eleccion as usize]
+                .votantes
+                .iter()
+                .any(|v| v.id == usuario.id)
+            {
+                return Err(Error::UsuarioYaRegistrado);
+            }
 
-    //----------------------Structs de usuarios---------------------------------------------------------
+            // Registrar al usuario completo como votante en la elección
+            self.elecciones[id_eleccion as usize].votantes.push(usuario);
+            Ok(())
+        }
 
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    pub enum RolUsuario {
-        Candidato,
-        Votante,
-    }
+        #[ink(message)]
+        /// Funcion para asignar el peso del voto de un votante registrado en una eleccion de mayoria, por ejemplo las acciones que tiene en una asamblea. Solo el admin puede asignarlo y solo antes de que la eleccion abra, si no se asigna el voto pesa 1
+        pub fn set_peso_votante(
+            &mut self,
+            id_eleccion: u64,
+            id_votante: AccountId,
+            peso: u64,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.set_peso_votante_priv(caller, id_eleccion, id_votante, peso)
+        }
+        fn set_peso_votante_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            id_votante: AccountId,
+            peso: u64,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
 
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    pub struct Usuario {
-        id: AccountId,
-        nombre: String,
-        email: String,
-        rol: RolUsuario,
-    }
-    impl Usuario {
-        pub fn new(id: AccountId, nombre: String, email: String, rol: RolUsuario) -> Self {
-            Self {
-                id,
-                nombre,
-                email,
-                rol,
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
             }
-        }
-    }
 
-    //----------------------Structs de fecha---------------------------------------------------------
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    pub struct Fecha {
-        dias: u32,
-        mes: u32,
-        anio: i32,
-    }
-    //----------------------Tests y errores---------------------------------------------------------
-    #[ink::scale_derive(Encode, Decode, TypeInfo)]
-    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    pub enum Error {
-        PermisoDenegado,
-        EleccionNoExiste,
-        EleccionAbierta,
-        EleccionNoActiva,
-        UsuarioNoVotante,
-        UsuarioYaRegistrado,
-        AdminNoPuedeRegistrarse,
-        CandidatoNoExiste,
-        UsuarioNoCandidato,
-        FechaInvalida,
-        Overflow,
-    }
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
+            }
 
-    impl core::fmt::Display for Error {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            match *self {
-                Error::PermisoDenegado => write!(f, "Permiso denegado"),
-                Error::EleccionNoExiste => write!(f, "La elección no existe"),
-                Error::EleccionAbierta => write!(f, "La elección está abierta"),
-                Error::EleccionNoActiva => write!(f, "La elección no está activa"),
-                Error::UsuarioNoVotante => write!(f, "El usuario no es votante"),
-                Error::UsuarioYaRegistrado => write!(f, "El usuario ya está registrado"),
-                Error::AdminNoPuedeRegistrarse => write!(f, "El admin no puede registrarse"),
-                Error::CandidatoNoExiste => write!(f, "El candidato no existe"),
-                Error::UsuarioNoCandidato => write!(f, "El usuario no es candidato"),
-                Error::FechaInvalida => write!(f, "Fecha inválida"),
-                Error::Overflow => write!(f, "Overflow"),
+            // Verificar que el votante este registrado en la eleccion y que el peso no sea cero
+            if !eleccion.votantes.iter().any(|v| v.id == id_votante) {
+                return Err(Error::UsuarioNoVotante);
             }
+            if peso == 0 {
+                return Err(Error::ConfiguracionInvalida);
+            }
+
+            eleccion.pesos.insert(id_votante, peso);
+            Ok(())
         }
-    }
 
-    #[cfg(test)]
-    mod test {
-        use super::*;
-        use ink::primitives::AccountId;
-        use ink_env::test::set_block_timestamp;
-        #[test]
-        fn test_fecha_to_timestamp() {
-            let mut fecha = Fecha {
-                dias: 1,
-                mes: 1,
-                anio: 2021,
-            };
-            assert_eq!(fecha.to_timestamp().unwrap(), 1609459200000);
-            fecha.dias = 32;
-            assert_eq!(fecha.to_timestamp().unwrap_err(), Error::FechaInvalida);
+        #[ink(message)]
+        /// Funcion para registrar un candidato en una eleccion con el id de la eleccion ingresado, solo puede ser llamada por un candidato y no puede registrarse dos veces en la misma eleccion
+        pub fn registrar_candidato_en_eleccion(&mut self, id_eleccion: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.registrar_candidato_en_eleccion_priv(caller, id_eleccion)
         }
-        #[test]
-        fn test_set_admin_priv() {
-            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
-            assert!(sistema
-                .set_admin_priv(
-                    AccountId::from([0x01; 32]),
+        fn registrar_candidato_en_eleccion_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+        ) -> Result<(), Error> {
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            // Verificar que la elección exista
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            // Las consultas no tienen candidatos, se vota una de sus opciones
+            if self.elecciones[id_eleccion as usize].modo == ModoEleccion::Consulta {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el usuario no esté registrado como candidato en la elección
+            if self.elecciones[id_eleccion as usize]
+                .candidatos
+                .contains_key(&caller)
+            {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Verificar que el usuario actual sea un candidato, si lo es, agregarlo a la lista de candidatos públicos de la elección
+            let mut usuario_es_candidato = false;
+            for usuario in self.usuarios.iter() {
+                if usuario.id == caller && usuario.rol == RolUsuario::Candidato {
+                    usuario_es_candidato = true;
+
+                    self.elecciones[id_eleccion as usize]
+                        .candidatos_publicos
+                        .push(usuario.clone());
+                    break;
+                }
+            }
+
+            // Si el usuario no es un candidato, lanzar un error
+            if !usuario_es_candidato {
+                return Err(Error::UsuarioNoCandidato);
+            }
+
+            // Registrar al usuario como candidato en la elección
+            self.elecciones[id_eleccion as usize]
+                .candidatos
+                .insert(caller, 0);
+            Ok(())
+        }
+
+        #[ink(message)]
+        /// Funcion para registrar una lista de candidatos en una eleccion por listas, solo el admin puede registrar listas y solo antes de que la eleccion abra. El orden de los candidatos es el orden en que entran a las bancas
+        pub fn registrar_lista(
+            &mut self,
+            id_eleccion: u64,
+            nombre: String,
+            candidatos: Vec<AccountId>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.registrar_lista_priv(caller, id_eleccion, nombre, candidatos)
+        }
+        fn registrar_lista_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            nombre: String,
+            candidatos: Vec<AccountId>,
+        ) -> Result<(), Error> {
+            // En una eleccion por listas cada lista es su propia agrupacion
+            self.agregar_lista(
+                caller,
+                id_eleccion,
+                nombre.clone(),
+                nombre,
+                candidatos,
+                false,
+            )
+        }
+
+        #[ink(message)]
+        /// Funcion para registrar una lista interna de una agrupacion en unas primarias, solo el admin puede registrar listas y solo antes de que la eleccion abra. Una agrupacion puede tener varias listas internas
+        pub fn registrar_lista_interna(
+            &mut self,
+            id_eleccion: u64,
+            agrupacion: String,
+            nombre: String,
+            candidatos: Vec<AccountId>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.registrar_lista_interna_priv(caller, id_eleccion, agrupacion, nombre, candidatos)
+        }
+        fn registrar_lista_interna_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            agrupacion: String,
+            nombre: String,
+            candidatos: Vec<AccountId>,
+        ) -> Result<(), Error> {
+            self.agregar_lista(caller, id_eleccion, agrupacion, nombre, candidatos, true)
+        }
+
+        /// Funcion que valida y agrega una lista a una eleccion por listas o, si es interna, a unas primarias
+        fn agregar_lista(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            agrupacion: String,
+            nombre: String,
+            candidatos: Vec<AccountId>,
+            interna: bool,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            let modo_correcto = if interna {
+                matches!(eleccion.modo, ModoEleccion::Primarias { .. })
+            } else {
+                matches!(eleccion.modo, ModoEleccion::Listas { .. })
+            };
+            if !modo_correcto {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que la lista tenga candidatos y que no haya otra lista con el mismo nombre
+            if candidatos.is_empty() || eleccion.listas.iter().any(|l| l.nombre == nombre) {
+                return Err(Error::ConfiguracionInvalida);
+            }
+
+            // Verificar que cada integrante sea un candidato y que no este repetido en esta ni en otra lista
+            let mut integrantes = Vec::new();
+            for (i, id_candidato) in candidatos.iter().enumerate() {
+                let usuario = match self.usuarios.iter().find(|u| u.id == *id_candidato) {
+                    Some(u) if u.rol == RolUsuario::Candidato => u,
+                    _ => return Err(Error::UsuarioNoCandidato),
+                };
+                if candidatos[..i].contains(id_candidato)
+                    || eleccion
+                        .listas
+                        .iter()
+                        .any(|l| l.candidatos.contains(id_candidato))
+                {
+                    return Err(Error::UsuarioYaRegistrado);
+                }
+                integrantes.push(usuario.clone());
+            }
+
+            // Si la eleccion o la general de estas primarias exige paridad los integrantes tienen que alternar de genero
+            if self.exige_paridad(eleccion) && !self.cumple_paridad(&candidatos) {
+                return Err(Error::ListaSinParidad);
+            }
+
+            // Los integrantes de la lista se muestran como candidatos de la eleccion
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            eleccion.candidatos_publicos.extend(integrantes);
+            eleccion.listas.push(Lista {
+                agrupacion,
+                nombre,
+                candidatos,
+                votos: 0,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        /// Funcion para agregar una categoria (cargo) a una eleccion de boleta unica, solo el admin puede agregar categorias y solo antes de que la eleccion abra. Las categorias se votan en el orden en que se agregan
+        pub fn agregar_categoria(&mut self, id_eleccion: u64, cargo: String) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.agregar_categoria_priv(caller, id_eleccion, cargo)
+        }
+        fn agregar_categoria_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            cargo: String,
+        ) -> Result<(), Error> {
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::BoletaUnica {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que no haya otra categoria con el mismo cargo
+            if eleccion.categorias.iter().any(|c| c.cargo == cargo) {
+                return Err(Error::ConfiguracionInvalida);
+            }
+
+            eleccion.categorias.push(Categoria {
+                cargo,
+                candidatos: BTreeMap::new(),
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        /// Funcion para que un candidato se registre en una categoria de una eleccion de boleta unica, un candidato solo puede competir en una categoria
+        pub fn registrar_candidato_en_categoria(
+            &mut self,
+            id_eleccion: u64,
+            nro_categoria: u64,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.registrar_candidato_en_categoria_priv(caller, id_eleccion, nro_categoria)
+        }
+        fn registrar_candidato_en_categoria_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            nro_categoria: u64,
+        ) -> Result<(), Error> {
+            // Verificar que la elección no esté abierta
+            if !self.eleccion_no_abierta(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            // Verificar que el usuario actual sea un candidato
+            let usuario = match self.usuarios.iter().find(|u| u.id == caller) {
+                Some(u) if u.rol == RolUsuario::Candidato => u.clone(),
+                _ => return Err(Error::UsuarioNoCandidato),
+            };
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::BoletaUnica {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el candidato no compita ya en alguna categoria
+            if eleccion
+                .categorias
+                .iter()
+                .any(|c| c.candidatos.contains_key(&caller))
+            {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Registrar al candidato en la categoria y mostrarlo como candidato de la eleccion
+            eleccion
+                .categorias
+                .get_mut(nro_categoria as usize)
+                .ok_or(Error::CategoriaNoExiste)?
+                .candidatos
+                .insert(caller, 0);
+            eleccion.candidatos_publicos.push(usuario);
+            Ok(())
+        }
+
+        //----------------------Funciones de votacion---------------------------------------------------------
+        #[ink(message)]
+        /// Funcion para votar en una eleccion con el id de la eleccion y el id del candidato ingresados, solo puede votar un votante y no puede votar dos veces en la misma eleccion. Devuelve un recibo calculado con el numero de voto, la boleta y la sal que elige el votante, que puede guardar para verificar con verificar_recibo que su voto se conto
+        pub fn votar(
+            &mut self,
+            id_eleccion: u64,
+            id_candidato: AccountId,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_priv(caller, id_eleccion, id_candidato, sal)
+        }
+        fn votar_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            id_candidato: AccountId,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("candidato", &id_candidato, sal)?;
+            eleccion.votar_en_eleccion(id_candidato, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para que cualquiera verifique que el voto con el recibo ingresado se sumo al conteo de la eleccion. Cada recibo es unico porque incluye el numero de voto, y el votante puede recalcularlo con calcular_recibo a partir de ese numero, su boleta y su sal para comprobar que se conto lo que voto
+        pub fn verificar_recibo(&self, id_eleccion: u64, recibo: [u8; 32]) -> Result<bool, Error> {
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize]
+                .recibos
+                .contains(&recibo))
+        }
+
+        #[ink(message)]
+        /// Funcion para comprometer el voto en una eleccion con voto secreto, el compromiso es el hash de (eleccion, votante, candidato, sal) calculado con calcular_compromiso y no revela a quien se voto
+        pub fn comprometer_voto(
+            &mut self,
+            id_eleccion: u64,
+            compromiso: [u8; 32],
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.comprometer_voto_priv(caller, id_eleccion, compromiso)
+        }
+        fn comprometer_voto_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            compromiso: [u8; 32],
+        ) -> Result<(), Error> {
+            let votante = self.buscar_votante_activo(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            if eleccion.fin_revelado.is_none() {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el votante no haya comprometido ya su voto
+            if eleccion.compromisos.contains_key(&votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // El distrito se verifica ahora para que el voto no falle recien al revelarlo
+            eleccion.distrito_del_votante(&votante.id)?;
+
+            eleccion.compromisos.insert(votante.id, compromiso);
+            Ok(())
+        }
+
+        #[ink(message)]
+        /// Funcion para revelar un voto comprometido despues del cierre de la eleccion y antes de fin_revelado, si el candidato y la sal coinciden con el compromiso el voto se suma al conteo y se devuelve su recibo
+        pub fn revelar_voto(
+            &mut self,
+            id_eleccion: u64,
+            id_candidato: AccountId,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.revelar_voto_priv(caller, id_eleccion, id_candidato, sal)
+        }
+        fn revelar_voto_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            id_candidato: AccountId,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            // Verificar que la elección ya haya cerrado
+            if !self.eleccion_cerrada(id_eleccion)? {
+                return Err(Error::EleccionAbierta);
+            }
+
+            let fecha_actual = self.env().block_timestamp();
+            let votante = match self.usuarios.iter().find(|&u| u.id == caller) {
+                Some(u) if u.rol == RolUsuario::Votante => u.clone(),
+                _ => return Err(Error::UsuarioNoVotante),
+            };
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            match eleccion.fin_revelado {
+                None => return Err(Error::ModoEleccionInvalido),
+                Some(fin) if fecha_actual > fin => return Err(Error::EleccionNoActiva),
+                _ => {}
+            }
+
+            let compromiso = *eleccion
+                .compromisos
+                .get(&votante.id)
+                .ok_or(Error::CompromisoNoExiste)?;
+            // El compromiso se recalcula con el que llama, asi nadie puede revelar un compromiso copiado de otro votante
+            if calcular_compromiso(id_eleccion, votante.id, id_candidato, sal) != compromiso {
+                return Err(Error::CompromisoInvalido);
+            }
+
+            // El recibo usa la sal del compromiso y es el mismo que el de un voto comun al candidato
+            let recibo = eleccion.recibo_del_voto("candidato", &id_candidato, sal)?;
+            eleccion.votar_en_eleccion(id_candidato, votante.clone())?;
+            eleccion.compromisos.remove(&votante.id);
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar en una eleccion preferencial, de borda o stv con el orden de preferencia de los candidatos, la boleta tiene que ordenar a todos los candidatos de la eleccion, devuelve el recibo del voto
+        pub fn votar_preferencial(
+            &mut self,
+            id_eleccion: u64,
+            preferencias: Vec<AccountId>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_preferencial_priv(caller, id_eleccion, preferencias, sal)
+        }
+        fn votar_preferencial_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            preferencias: Vec<AccountId>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("preferencial", &preferencias, sal)?;
+            eleccion.votar_preferencial_en_eleccion(preferencias, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar en una eleccion por aprobacion con los candidatos que el votante aprueba, cada candidato aprobado suma un voto, devuelve el recibo del voto
+        pub fn votar_aprobacion(
+            &mut self,
+            id_eleccion: u64,
+            aprobados: Vec<AccountId>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_aprobacion_priv(caller, id_eleccion, aprobados, sal)
+        }
+        fn votar_aprobacion_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            aprobados: Vec<AccountId>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("aprobacion", &aprobados, sal)?;
+            eleccion.votar_aprobacion_en_eleccion(aprobados, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar en una eleccion de puntaje dandole un puntaje entre 0 y el maximo de la eleccion a cada candidato registrado, devuelve el recibo del voto
+        pub fn votar_puntaje(
+            &mut self,
+            id_eleccion: u64,
+            puntajes: Vec<(AccountId, u32)>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_puntaje_priv(caller, id_eleccion, puntajes, sal)
+        }
+        fn votar_puntaje_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            puntajes: Vec<(AccountId, u32)>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("puntaje", &puntajes, sal)?;
+            eleccion.votar_puntaje_en_eleccion(puntajes, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar en una eleccion cuadratica repartiendo votos entre los candidatos, k votos a un candidato cuestan k² creditos y el total no puede pasar los creditos de la eleccion, devuelve el recibo del voto
+        pub fn votar_cuadratico(
+            &mut self,
+            id_eleccion: u64,
+            votos: Vec<(AccountId, u32)>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_cuadratico_priv(caller, id_eleccion, votos, sal)
+        }
+        fn votar_cuadratico_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            votos: Vec<(AccountId, u32)>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("cuadratico", &votos, sal)?;
+            eleccion.votar_cuadratico_en_eleccion(votos, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar en una eleccion acumulativa repartiendo los votos de la eleccion entre los candidatos como quiera, incluso todos a uno solo, devuelve el recibo del voto
+        pub fn votar_acumulativo(
+            &mut self,
+            id_eleccion: u64,
+            votos: Vec<(AccountId, u32)>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_acumulativo_priv(caller, id_eleccion, votos, sal)
+        }
+        fn votar_acumulativo_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            votos: Vec<(AccountId, u32)>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("acumulativo", &votos, sal)?;
+            eleccion.votar_acumulativo_en_eleccion(votos, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar una lista en una eleccion por listas con el numero de la lista en el orden en que se registraron, devuelve el recibo del voto
+        pub fn votar_lista(
+            &mut self,
+            id_eleccion: u64,
+            nro_lista: u64,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_lista_priv(caller, id_eleccion, nro_lista, sal)
+        }
+        fn votar_lista_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            nro_lista: u64,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("lista", &nro_lista, sal)?;
+            eleccion.votar_lista_en_eleccion(nro_lista, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar escribiendo el nombre de un candidato que no esta registrado, solo en elecciones de mayoria que lo permitan. El nombre se normaliza para que las distintas formas de escribirlo cuenten juntas, devuelve el recibo del voto
+        pub fn votar_candidato_escrito(
+            &mut self,
+            id_eleccion: u64,
+            nombre: String,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_candidato_escrito_priv(caller, id_eleccion, nombre, sal)
+        }
+        fn votar_candidato_escrito_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            nombre: String,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("escrito", &normalizar_nombre(&nombre), sal)?;
+            eleccion.votar_candidato_escrito_en_eleccion(nombre, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar en blanco en una eleccion de cualquier modo salvo con voto secreto, el votante queda registrado como que voto, devuelve el recibo del voto
+        pub fn votar_en_blanco(
+            &mut self,
+            id_eleccion: u64,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_en_blanco_priv(caller, id_eleccion, sal)
+        }
+        fn votar_en_blanco_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("blanco", &(), sal)?;
+            eleccion.votar_en_blanco_en_eleccion(votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para anular el voto en una eleccion de cualquier modo salvo con voto secreto, el votante queda registrado como que voto pero el voto no es valido, devuelve el recibo del voto
+        pub fn votar_nulo(&mut self, id_eleccion: u64, sal: [u8; 32]) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_nulo_priv(caller, id_eleccion, sal)
+        }
+        fn votar_nulo_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("nulo", &(), sal)?;
+            eleccion.votar_nulo_en_eleccion(votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar una opcion en una consulta popular con el numero de la opcion en el orden en que se crearon, devuelve el recibo del voto
+        pub fn votar_opcion(
+            &mut self,
+            id_eleccion: u64,
+            nro_opcion: u64,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_opcion_priv(caller, id_eleccion, nro_opcion, sal)
+        }
+        fn votar_opcion_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            nro_opcion: u64,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("opcion", &nro_opcion, sal)?;
+            eleccion.votar_opcion_en_eleccion(nro_opcion, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        #[ink(message)]
+        /// Funcion para votar en una eleccion de boleta unica con un candidato por categoria en el orden de las categorias, si algun voto es invalido no se registra ninguno, devuelve el recibo del voto
+        pub fn votar_boleta_unica(
+            &mut self,
+            id_eleccion: u64,
+            boleta: Vec<AccountId>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let caller = self.env().caller();
+            self.votar_boleta_unica_priv(caller, id_eleccion, boleta, sal)
+        }
+        fn votar_boleta_unica_priv(
+            &mut self,
+            caller: AccountId,
+            id_eleccion: u64,
+            boleta: Vec<AccountId>,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let votante = self.validar_votante(caller, id_eleccion)?;
+
+            let eleccion = &mut self.elecciones[id_eleccion as usize];
+            let recibo = eleccion.recibo_del_voto("boleta_unica", &boleta, sal)?;
+            eleccion.votar_boleta_unica_en_eleccion(boleta, votante)?;
+            eleccion.recibos.push(recibo);
+            Ok(recibo)
+        }
+
+        /// Funcion que verifica que la eleccion este activa, que el que llama sea un votante y que la eleccion no tenga voto secreto, devuelve el usuario del votante
+        fn validar_votante(&self, caller: AccountId, id_eleccion: u64) -> Result<Usuario, Error> {
+            let votante = self.buscar_votante_activo(caller, id_eleccion)?;
+
+            // con voto secreto solo se puede comprometer el voto y revelarlo despues del cierre
+            if self.elecciones[id_eleccion as usize].fin_revelado.is_some() {
+                return Err(Error::VotoSecreto);
+            }
+
+            Ok(votante)
+        }
+
+        /// Funcion que verifica que la eleccion este activa y que el que llama sea un votante, devuelve el usuario del votante
+        fn buscar_votante_activo(
+            &self,
+            caller: AccountId,
+            id_eleccion: u64,
+        ) -> Result<Usuario, Error> {
+            // Verificar que la elección esté activa
+            if !self.eleccion_activa(id_eleccion)? {
+                return Err(Error::EleccionNoActiva);
+            }
+
+            //buscar al votante en el vector de usuarios y verificar que sea un votante
+            match self.usuarios.iter().find(|&u| u.id == caller) {
+                Some(u) if u.rol == RolUsuario::Votante => Ok(u.clone()),
+                _ => Err(Error::UsuarioNoVotante),
+            }
+        }
+
+        //----------------------Funciones para el reporte---------------------------------------------------------
+        // en estas funciones tengo que verificar que el que llama sea el contrato de reporte y no otro contrato o usuario
+
+        #[ink(message)]
+        /// Funcion para settear el id del contrato de reporte en el sistema de votacion, solo puede ser setteado por el admin. Esto sirve para que el contrato de reporte pueda acceder a los datos del sistema de votacion
+        pub fn set_id_contrato(&mut self, id: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if self.admin.id != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            self.id_contrato_reporte = id;
+            Ok(())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener el id del contrato de reporte solo puede ser llamada por el contrato de reporte
+        pub fn get_tamanio_elecciones(&self) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+            Ok(self.elecciones.len() as u64)
+        }
+
+        fn get_candidatos_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<BTreeMap<AccountId, u64>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].candidatos.clone())
+        }
+
+        #[ink(message)]
+        ///Funcion para obtener los candidatos con sus votos de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_candidatos(&self, id_eleccion: u64) -> Result<BTreeMap<AccountId, u64>, Error> {
+            let caller = self.env().caller();
+            self.get_candidatos_priv(id_eleccion, caller)
+        }
+
+        fn get_votantes_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<Usuario>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].votantes.clone())
+        }
+
+        fn get_boletas_preferenciales_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<Vec<AccountId>>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if !matches!(
+                eleccion.modo,
+                ModoEleccion::Preferencial | ModoEleccion::Borda | ModoEleccion::Stv { .. }
+            ) {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            Ok(eleccion.boletas_preferenciales.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener las boletas con el orden de preferencias de una eleccion preferencial, de borda o stv solo puede ser llamada por el contrato de reporte
+        pub fn get_boletas_preferenciales(
+            &self,
+            id_eleccion: u64,
+        ) -> Result<Vec<Vec<AccountId>>, Error> {
+            let caller = self.env().caller();
+            self.get_boletas_preferenciales_priv(id_eleccion, caller)
+        }
+
+        fn get_boletas_puntaje_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<Vec<(AccountId, u32)>>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if !matches!(eleccion.modo, ModoEleccion::Puntaje { .. }) {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            Ok(eleccion.boletas_puntaje.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener las boletas con los puntajes de cada votante de una eleccion de puntaje solo puede ser llamada por el contrato de reporte
+        pub fn get_boletas_puntaje(
+            &self,
+            id_eleccion: u64,
+        ) -> Result<Vec<Vec<(AccountId, u32)>>, Error> {
+            let caller = self.env().caller();
+            self.get_boletas_puntaje_priv(id_eleccion, caller)
+        }
+
+        fn get_creditos_gastados_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<BTreeMap<AccountId, u64>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if !matches!(eleccion.modo, ModoEleccion::Cuadratico { .. }) {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            Ok(eleccion.creditos_gastados.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener los creditos que gastaron los votantes en cada candidato de una eleccion cuadratica solo puede ser llamada por el contrato de reporte
+        pub fn get_creditos_gastados(
+            &self,
+            id_eleccion: u64,
+        ) -> Result<BTreeMap<AccountId, u64>, Error> {
+            let caller = self.env().caller();
+            self.get_creditos_gastados_priv(id_eleccion, caller)
+        }
+
+        fn get_boletas_acumulativas_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<BTreeMap<AccountId, Vec<(AccountId, u32)>>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if !matches!(eleccion.modo, ModoEleccion::Acumulativo { .. }) {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            Ok(eleccion.boletas_acumulativas.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener como repartio sus votos cada votante de una eleccion acumulativa solo puede ser llamada por el contrato de reporte
+        pub fn get_boletas_acumulativas(
+            &self,
+            id_eleccion: u64,
+        ) -> Result<BTreeMap<AccountId, Vec<(AccountId, u32)>>, Error> {
+            let caller = self.env().caller();
+            self.get_boletas_acumulativas_priv(id_eleccion, caller)
+        }
+
+        fn get_listas_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<Lista>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].listas.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener las listas con sus votos de una eleccion por listas solo puede ser llamada por el contrato de reporte
+        pub fn get_listas(&self, id_eleccion: u64) -> Result<Vec<Lista>, Error> {
+            let caller = self.env().caller();
+            self.get_listas_priv(id_eleccion, caller)
+        }
+
+        fn get_categorias_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<Categoria>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].categorias.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener las categorias con los votos de sus candidatos de una eleccion de boleta unica solo puede ser llamada por el contrato de reporte
+        pub fn get_categorias(&self, id_eleccion: u64) -> Result<Vec<Categoria>, Error> {
+            let caller = self.env().caller();
+            self.get_categorias_priv(id_eleccion, caller)
+        }
+
+        fn get_opciones_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<(String, u64)>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            let eleccion = &self.elecciones[id_eleccion as usize];
+            if eleccion.modo != ModoEleccion::Consulta {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            Ok(eleccion.opciones.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener las opciones con sus votos de una consulta popular solo puede ser llamada por el contrato de reporte
+        pub fn get_opciones(&self, id_eleccion: u64) -> Result<Vec<(String, u64)>, Error> {
+            let caller = self.env().caller();
+            self.get_opciones_priv(id_eleccion, caller)
+        }
+
+        fn get_votos_por_cabeza_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<BTreeMap<AccountId, u64>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize]
+                .votos_por_cabeza
+                .clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener cuantos votantes eligieron a cada candidato de una eleccion de mayoria sin contar el peso de sus votos solo puede ser llamada por el contrato de reporte
+        pub fn get_votos_por_cabeza(
+            &self,
+            id_eleccion: u64,
+        ) -> Result<BTreeMap<AccountId, u64>, Error> {
+            let caller = self.env().caller();
+            self.get_votos_por_cabeza_priv(id_eleccion, caller)
+        }
+
+        fn get_votos_escritos_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<BTreeMap<String, u64>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].votos_escritos.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener los votos de los candidatos escritos por los votantes de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_votos_escritos(&self, id_eleccion: u64) -> Result<BTreeMap<String, u64>, Error> {
+            let caller = self.env().caller();
+            self.get_votos_escritos_priv(id_eleccion, caller)
+        }
+
+        fn get_votos_en_blanco_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<u64, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].votos_en_blanco)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener la cantidad de votos en blanco de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_votos_en_blanco(&self, id_eleccion: u64) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            self.get_votos_en_blanco_priv(id_eleccion, caller)
+        }
+
+        fn get_votos_nulos_priv(&self, id_eleccion: u64, caller: AccountId) -> Result<u64, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].votos_nulos)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener la cantidad de votos nulos de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_votos_nulos(&self, id_eleccion: u64) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            self.get_votos_nulos_priv(id_eleccion, caller)
+        }
+
+        fn get_votos_en_blanco_por_cabeza_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<u64, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].votos_en_blanco_por_cabeza)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener cuantos votantes votaron en blanco en una eleccion sin contar el peso de sus votos solo puede ser llamada por el contrato de reporte
+        pub fn get_votos_en_blanco_por_cabeza(&self, id_eleccion: u64) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            self.get_votos_en_blanco_por_cabeza_priv(id_eleccion, caller)
+        }
+
+        fn get_votos_nulos_por_cabeza_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<u64, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].votos_nulos_por_cabeza)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener cuantos votantes anularon su voto en una eleccion sin contar el peso de sus votos solo puede ser llamada por el contrato de reporte
+        pub fn get_votos_nulos_por_cabeza(&self, id_eleccion: u64) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            self.get_votos_nulos_por_cabeza_priv(id_eleccion, caller)
+        }
+
+        fn get_modo_eleccion_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<ModoEleccion, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].modo.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener el modo de votacion de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_modo_eleccion(&self, id_eleccion: u64) -> Result<ModoEleccion, Error> {
+            let caller = self.env().caller();
+            self.get_modo_eleccion_priv(id_eleccion, caller)
+        }
+
+        fn get_quorum_priv(&self, id_eleccion: u64, caller: AccountId) -> Result<u32, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].quorum)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener el quorum de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_quorum(&self, id_eleccion: u64) -> Result<u32, Error> {
+            let caller = self.env().caller();
+            self.get_quorum_priv(id_eleccion, caller)
+        }
+
+        fn get_mayoria_requerida_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<MayoriaRequerida, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize]
+                .mayoria_requerida
+                .clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener la mayoria que necesita el ganador de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_mayoria_requerida(&self, id_eleccion: u64) -> Result<MayoriaRequerida, Error> {
+            let caller = self.env().caller();
+            self.get_mayoria_requerida_priv(id_eleccion, caller)
+        }
+
+        fn get_distritos_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<Distrito>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].distritos.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener los distritos con sus electores y los votos de cada candidato en el distrito solo puede ser llamada por el contrato de reporte
+        pub fn get_distritos(&self, id_eleccion: u64) -> Result<Vec<Distrito>, Error> {
+            let caller = self.env().caller();
+            self.get_distritos_priv(id_eleccion, caller)
+        }
+
+        fn get_regla_distrital_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<ReglaDistrital, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize]
+                .regla_distrital
+                .clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener como se reparten los electores de los distritos de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_regla_distrital(&self, id_eleccion: u64) -> Result<ReglaDistrital, Error> {
+            let caller = self.env().caller();
+            self.get_regla_distrital_priv(id_eleccion, caller)
+        }
+
+        fn get_fin_revelado_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Option<u64>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].fin_revelado)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener hasta cuando se pueden revelar los votos de una eleccion con voto secreto, None si el voto no es secreto solo puede ser llamada por el contrato de reporte
+        pub fn get_fin_revelado(&self, id_eleccion: u64) -> Result<Option<u64>, Error> {
+            let caller = self.env().caller();
+            self.get_fin_revelado_priv(id_eleccion, caller)
+        }
+
+        fn get_compromisos_sin_revelar_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<AccountId>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize]
+                .compromisos
+                .keys()
+                .copied()
+                .collect())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener los votantes que comprometieron su voto y todavia no lo revelaron solo puede ser llamada por el contrato de reporte
+        pub fn get_compromisos_sin_revelar(
+            &self,
+            id_eleccion: u64,
+        ) -> Result<Vec<AccountId>, Error> {
+            let caller = self.env().caller();
+            self.get_compromisos_sin_revelar_priv(id_eleccion, caller)
+        }
+
+        fn get_politica_desempate_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<PoliticaDesempate, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize]
+                .politica_desempate
+                .clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener la politica de desempate de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_politica_desempate(&self, id_eleccion: u64) -> Result<PoliticaDesempate, Error> {
+            let caller = self.env().caller();
+            self.get_politica_desempate_priv(id_eleccion, caller)
+        }
+
+        fn get_decision_desempate_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Option<AccountId>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].decision_desempate)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener el candidato que eligio el admin para desempatar una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_decision_desempate(&self, id_eleccion: u64) -> Result<Option<AccountId>, Error> {
+            let caller = self.env().caller();
+            self.get_decision_desempate_priv(id_eleccion, caller)
+        }
+
+        fn get_segunda_vuelta_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Option<u64>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].segunda_vuelta)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener el id de la segunda vuelta creada a partir de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_segunda_vuelta(&self, id_eleccion: u64) -> Result<Option<u64>, Error> {
+            let caller = self.env().caller();
+            self.get_segunda_vuelta_priv(id_eleccion, caller)
+        }
+
+        fn get_orden_candidatos_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<AccountId>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize]
+                .candidatos_publicos
+                .iter()
+                .map(|u| u.id)
+                .collect())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener los candidatos de una eleccion en el orden en que se registraron solo puede ser llamada por el contrato de reporte
+        pub fn get_orden_candidatos(&self, id_eleccion: u64) -> Result<Vec<AccountId>, Error> {
+            let caller = self.env().caller();
+            self.get_orden_candidatos_priv(id_eleccion, caller)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener los votantes registrados de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_votantes(&self, id_eleccion: u64) -> Result<Vec<Usuario>, Error> {
+            let caller = self.env().caller();
+            self.get_votantes_priv(id_eleccion, caller)
+        }
+
+        fn get_votantes_que_votaron_priv(
+            &self,
+            id_eleccion: u64,
+            caller: AccountId,
+        ) -> Result<Vec<Usuario>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+            Ok(self.elecciones[id_eleccion as usize]
+                .votantes_que_votaron
+                .clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener los votantes que votaron de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_votantes_que_votaron(&self, id_eleccion: u64) -> Result<Vec<Usuario>, Error> {
+            let caller = self.env().caller();
+            self.get_votantes_que_votaron_priv(id_eleccion, caller)
+        }
+
+        fn get_usuarios_priv(&self, caller: AccountId) -> Result<Vec<Usuario>, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+            Ok(self.usuarios.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener los usuarios registrados en el sistema, pueden ser votantes o candidatos. Solo puede ser llamada por el contrato de reporte
+        pub fn get_usuarios(&self) -> Result<Vec<Usuario>, Error> {
+            let caller = self.env().caller();
+            self.get_usuarios_priv(caller)
+        }
+
+        fn get_fecha_inicio_priv(&self, id_eleccion: u64, caller: AccountId) -> Result<u64, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].fecha_inicio)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener la fecha de inicio de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_fecha_inicio(&self, id_eleccion: u64) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            self.get_fecha_inicio_priv(id_eleccion, caller)
+        }
+
+        fn get_fecha_fin_priv(&self, id_eleccion: u64, caller: AccountId) -> Result<u64, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].fecha_fin)
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener la fecha de fin de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_fecha_fin(&self, id_eleccion: u64) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            self.get_fecha_fin_priv(id_eleccion, caller)
+        }
+
+        fn get_cargo_priv(&self, id_eleccion: u64, caller: AccountId) -> Result<String, Error> {
+            if self.id_contrato_reporte != caller {
+                return Err(Error::PermisoDenegado);
+            }
+
+            if id_eleccion as usize >= self.elecciones.len() {
+                return Err(Error::EleccionNoExiste);
+            }
+
+            Ok(self.elecciones[id_eleccion as usize].cargo.clone())
+        }
+
+        #[ink(message)]
+        /// Funcion para obtener el cargo de una eleccion solo puede ser llamada por el contrato de reporte
+        pub fn get_cargo(&self, id_eleccion: u64) -> Result<String, Error> {
+            let caller = self.env().caller();
+            self.get_cargo_priv(id_eleccion, caller)
+        }
+    }
+    //----------------------Funciones de eleccion---------------------------------------------------------
+    impl Eleccion {
+        fn new(id: u64, cargo: String, fecha_inicio: u64, fecha_fin: u64) -> Self {
+            Self {
+                id,
+                cargo,
+                fecha_inicio,
+                fecha_fin,
+                candidatos: BTreeMap::new(),
+                candidatos_publicos: Vec::new(),
+                votantes: Vec::new(),
+                votantes_que_votaron: Vec::new(),
+                modo: ModoEleccion::Mayoria,
+                boletas_preferenciales: Vec::new(),
+                boletas_puntaje: Vec::new(),
+                listas: Vec::new(),
+                votos_en_blanco: 0,
+                votos_nulos: 0,
+                votos_en_blanco_por_cabeza: 0,
+                votos_nulos_por_cabeza: 0,
+                segunda_vuelta: None,
+                opciones: Vec::new(),
+                categorias: Vec::new(),
+                permite_candidatos_escritos: false,
+                votos_escritos: BTreeMap::new(),
+                pesos: BTreeMap::new(),
+                votos_por_cabeza: BTreeMap::new(),
+                creditos_gastados: BTreeMap::new(),
+                boletas_acumulativas: BTreeMap::new(),
+                politica_desempate: PoliticaDesempate::DeclararEmpate,
+                decision_desempate: None,
+                listas_promovidas: false,
+                quorum: 0,
+                mayoria_requerida: MayoriaRequerida::Simple,
+                exige_paridad: false,
+                distritos: Vec::new(),
+                distrito_votantes: BTreeMap::new(),
+                regla_distrital: ReglaDistrital::GanadorSeLlevaTodo,
+                fin_revelado: None,
+                compromisos: BTreeMap::new(),
+                recibos: Vec::new(),
+            }
+        }
+
+        fn votar_en_eleccion(
+            &mut self,
+            id_candidato: AccountId,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea de mayoria simple
+            if self.modo != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // El voto suma el peso del votante, 1 si no tiene uno asignado
+            let peso = self.pesos.get(&votante.id).copied().unwrap_or(1);
+
+            let distrito = self.distrito_del_votante(&votante.id)?;
+
+            // Incrementar el conteo de votos del candidato
+            // Verificar que el candidato exista
+            if let Some(votos) = self.candidatos.get_mut(&id_candidato) {
+                // Verificar que el votante no haya votado ya
+                if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                    return Err(Error::UsuarioYaRegistrado);
+                }
+
+                // Intentar incrementar el conteo de votos, manejando el posible overflow
+                *votos = votos.checked_add(peso).ok_or(Error::Overflow)?;
+
+                // Llevar aparte la cantidad de votantes que eligieron al candidato
+                let votantes = self.votos_por_cabeza.entry(id_candidato).or_insert(0);
+                *votantes = votantes.checked_add(1).ok_or(Error::Overflow)?;
+
+                // Sumar el voto tambien en el distrito del votante
+                if let Some(distrito) = distrito {
+                    let votos_distrito = self.distritos[distrito as usize]
+                        .candidatos
+                        .entry(id_candidato)
+                        .or_insert(0);
+                    *votos_distrito = votos_distrito.checked_add(peso).ok_or(Error::Overflow)?;
+                }
+
+                // Agregar al votante a la lista de votantes que votaron
+                self.votantes_que_votaron.push(votante);
+            } else {
+                return Err(Error::CandidatoNoExiste);
+            }
+            Ok(())
+        }
+
+        /// Devuelve los candidatos que comparten la mayor cantidad de votos, si nadie tiene votos no hay empate
+        fn candidatos_empatados(&self) -> Vec<AccountId> {
+            let maximo = self.candidatos.values().copied().max().unwrap_or(0);
+            if maximo == 0 {
+                return Vec::new();
+            }
+            self.candidatos
+                .iter()
+                .filter(|(_, votos)| **votos == maximo)
+                .map(|(id, _)| *id)
+                .collect()
+        }
+
+        /// Funcion que calcula el recibo del proximo voto que se va a contar, el numero de voto es la cantidad de votantes que ya votaron. Si el recibo ya existe devuelve error antes de que se sume el voto
+        fn recibo_del_voto<T: scale::Encode>(
+            &self,
+            tipo: &str,
+            boleta: &T,
+            sal: [u8; 32],
+        ) -> Result<[u8; 32], Error> {
+            let recibo = calcular_recibo(
+                self.id,
+                self.votantes_que_votaron.len() as u64,
+                tipo,
+                boleta,
+                sal,
+            );
+            if self.recibos.contains(&recibo) {
+                return Err(Error::ReciboDuplicado);
+            }
+            Ok(recibo)
+        }
+
+        /// Funcion que devuelve el distrito del votante, None si la eleccion no tiene distritos. Si tiene distritos el votante tiene que tener uno asignado
+        fn distrito_del_votante(&self, id_votante: &AccountId) -> Result<Option<u64>, Error> {
+            if self.distritos.is_empty() {
+                return Ok(None);
+            }
+            self.distrito_votantes
+                .get(id_votante)
+                .copied()
+                .map(Some)
+                .ok_or(Error::VotanteSinDistrito)
+        }
+
+        fn votar_candidato_escrito_en_eleccion(
+            &mut self,
+            nombre: String,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea de mayoria y que permita candidatos escritos
+            if self.modo != ModoEleccion::Mayoria {
+                return Err(Error::ModoEleccionInvalido);
+            }
+            if !self.permite_candidatos_escritos {
+                return Err(Error::CandidatosEscritosNoPermitidos);
+            }
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+            self.distrito_del_votante(&votante.id)?;
+
+            // Verificar que el nombre no quede vacio al normalizarlo
+            let nombre = normalizar_nombre(&nombre);
+            if nombre.is_empty() {
+                return Err(Error::BoletaInvalida);
+            }
+
+            // El candidato escrito suma el peso del votante igual que los candidatos registrados
+            let peso = self.pesos.get(&votante.id).copied().unwrap_or(1);
+            let votos = self.votos_escritos.entry(nombre).or_insert(0);
+            *votos = votos.checked_add(peso).ok_or(Error::Overflow)?;
+
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_preferencial_en_eleccion(
+            &mut self,
+            preferencias: Vec<AccountId>,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea preferencial, de borda o stv, todas se votan ordenando a los candidatos
+            if !matches!(
+                self.modo,
+                ModoEleccion::Preferencial | ModoEleccion::Borda | ModoEleccion::Stv { .. }
+            ) {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Verificar que la boleta ordene a todos los candidatos una sola vez
+            if preferencias.is_empty() || preferencias.len() != self.candidatos.len() {
+                return Err(Error::BoletaInvalida);
+            }
+            for (i, id_candidato) in preferencias.iter().enumerate() {
+                if !self.candidatos.contains_key(id_candidato) {
+                    return Err(Error::CandidatoNoExiste);
+                }
+                if preferencias[..i].contains(id_candidato) {
+                    return Err(Error::BoletaInvalida);
+                }
+            }
+
+            if self.modo == ModoEleccion::Borda {
+                // En borda cada posicion suma puntos, n-1 el primero, n-2 el segundo y asi hasta 0 el ultimo
+                let cantidad = preferencias.len();
+                for (i, id_candidato) in preferencias.iter().enumerate() {
+                    if let Some(votos) = self.candidatos.get_mut(id_candidato) {
+                        let puntos = (cantidad - 1 - i) as u64;
+                        *votos = votos.checked_add(puntos).ok_or(Error::Overflow)?;
+                    }
+                }
+            } else if let Some(votos) = self.candidatos.get_mut(&preferencias[0]) {
+                // La primera preferencia se suma al conteo de votos, asi el reporte de resultados muestra las primeras preferencias
+                *votos = votos.checked_add(1).ok_or(Error::Overflow)?;
+            }
+
+            self.boletas_preferenciales.push(preferencias);
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_aprobacion_en_eleccion(
+            &mut self,
+            aprobados: Vec<AccountId>,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea por aprobacion
+            if self.modo != ModoEleccion::Aprobacion {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            if aprobados.is_empty() {
+                return Err(Error::BoletaInvalida);
+            }
+
+            // Verificar todos los candidatos antes de sumar votos, un candidato repetido o inexistente invalida la boleta completa
+            for (i, id_candidato) in aprobados.iter().enumerate() {
+                if !self.candidatos.contains_key(id_candidato)
+                    || aprobados[..i].contains(id_candidato)
+                {
+                    return Err(Error::CandidatoNoExiste);
+                }
+            }
+
+            for id_candidato in aprobados.iter() {
+                if let Some(votos) = self.candidatos.get_mut(id_candidato) {
+                    *votos = votos.checked_add(1).ok_or(Error::Overflow)?;
+                }
+            }
+
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_puntaje_en_eleccion(
+            &mut self,
+            puntajes: Vec<(AccountId, u32)>,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea de puntaje
+            let maximo = match self.modo {
+                ModoEleccion::Puntaje { maximo, .. } => maximo,
+                _ => return Err(Error::ModoEleccionInvalido),
+            };
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Verificar que la boleta puntue a todos los candidatos una sola vez y sin pasarse del maximo
+            if puntajes.is_empty() || puntajes.len() != self.candidatos.len() {
+                return Err(Error::BoletaInvalida);
+            }
+            for (i, (id_candidato, puntaje)) in puntajes.iter().enumerate() {
+                if !self.candidatos.contains_key(id_candidato) {
+                    return Err(Error::CandidatoNoExiste);
+                }
+                if *puntaje > maximo || puntajes[..i].iter().any(|(c, _)| c == id_candidato) {
+                    return Err(Error::BoletaInvalida);
+                }
+            }
+
+            // El puntaje se suma al conteo de votos, asi el reporte de resultados muestra el puntaje total de cada candidato
+            for (id_candidato, puntaje) in puntajes.iter() {
+                if let Some(votos) = self.candidatos.get_mut(id_candidato) {
+                    *votos = votos
+                        .checked_add(u64::from(*puntaje))
+                        .ok_or(Error::Overflow)?;
+                }
+            }
+
+            self.boletas_puntaje.push(puntajes);
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_cuadratico_en_eleccion(
+            &mut self,
+            votos: Vec<(AccountId, u32)>,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea cuadratica
+            let creditos = match self.modo {
+                ModoEleccion::Cuadratico { creditos } => creditos,
+                _ => return Err(Error::ModoEleccionInvalido),
+            };
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Verificar que cada candidato exista y aparezca una sola vez, y calcular el costo de cada uno
+            if votos.is_empty() {
+                return Err(Error::BoletaInvalida);
+            }
+            let mut costos = Vec::new();
+            let mut costo_total: u64 = 0;
+            for (i, (id_candidato, cantidad)) in votos.iter().enumerate() {
+                if !self.candidatos.contains_key(id_candidato) {
+                    return Err(Error::CandidatoNoExiste);
+                }
+                if votos[..i].iter().any(|(c, _)| c == id_candidato) {
+                    return Err(Error::BoletaInvalida);
+                }
+                let costo = u64::from(*cantidad)
+                    .checked_mul(u64::from(*cantidad))
+                    .ok_or(Error::Overflow)?;
+                costo_total = costo_total.checked_add(costo).ok_or(Error::Overflow)?;
+                costos.push(costo);
+            }
+
+            // Verificar que el votante no gaste mas creditos de los que tiene
+            if costo_total > creditos {
+                return Err(Error::CreditosInsuficientes);
+            }
+
+            for ((id_candidato, cantidad), costo) in votos.iter().zip(costos) {
+                if let Some(conteo) = self.candidatos.get_mut(id_candidato) {
+                    *conteo = conteo
+                        .checked_add(u64::from(*cantidad))
+                        .ok_or(Error::Overflow)?;
+                }
+                let gastados = self.creditos_gastados.entry(*id_candidato).or_insert(0);
+                *gastados = gastados.checked_add(costo).ok_or(Error::Overflow)?;
+            }
+
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_acumulativo_en_eleccion(
+            &mut self,
+            votos: Vec<(AccountId, u32)>,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea acumulativa
+            let (votos_por_votante, exige_total) = match self.modo {
+                ModoEleccion::Acumulativo {
+                    votos_por_votante,
+                    exige_total,
+                    ..
+                } => (votos_por_votante, exige_total),
+                _ => return Err(Error::ModoEleccionInvalido),
+            };
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Verificar que cada candidato exista y aparezca una sola vez
+            let mut total: u64 = 0;
+            for (i, (id_candidato, cantidad)) in votos.iter().enumerate() {
+                if !self.candidatos.contains_key(id_candidato) {
+                    return Err(Error::CandidatoNoExiste);
+                }
+                if votos[..i].iter().any(|(c, _)| c == id_candidato) {
+                    return Err(Error::BoletaInvalida);
+                }
+                total = total
+                    .checked_add(u64::from(*cantidad))
+                    .ok_or(Error::Overflow)?;
+            }
+
+            // Verificar que use todos sus votos si la eleccion lo exige, o que no use mas de los que tiene
+            let votos_por_votante = u64::from(votos_por_votante);
+            if total == 0
+                || total > votos_por_votante
+                || (exige_total && total != votos_por_votante)
+            {
+                return Err(Error::BoletaInvalida);
+            }
+
+            for (id_candidato, cantidad) in votos.iter() {
+                if let Some(conteo) = self.candidatos.get_mut(id_candidato) {
+                    *conteo = conteo
+                        .checked_add(u64::from(*cantidad))
+                        .ok_or(Error::Overflow)?;
+                }
+            }
+
+            self.boletas_acumulativas.insert(votante.id, votos);
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_opcion_en_eleccion(
+            &mut self,
+            nro_opcion: u64,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea una consulta
+            if self.modo != ModoEleccion::Consulta {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Verificar que la opcion exista e incrementar sus votos
+            let (_, votos) = self
+                .opciones
+                .get_mut(nro_opcion as usize)
+                .ok_or(Error::OpcionNoExiste)?;
+            *votos = votos.checked_add(1).ok_or(Error::Overflow)?;
+
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_en_blanco_en_eleccion(&mut self, votante: Usuario) -> Result<(), Error> {
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+            self.distrito_del_votante(&votante.id)?;
+
+            // El voto blanco tambien suma el peso del votante para que se compare con los votos a candidatos
+            let peso = self.pesos.get(&votante.id).copied().unwrap_or(1);
+            self.votos_en_blanco = self
+                .votos_en_blanco
+                .checked_add(peso)
+                .ok_or(Error::Overflow)?;
+            self.votos_en_blanco_por_cabeza = self
+                .votos_en_blanco_por_cabeza
+                .checked_add(1)
+                .ok_or(Error::Overflow)?;
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_nulo_en_eleccion(&mut self, votante: Usuario) -> Result<(), Error> {
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+            self.distrito_del_votante(&votante.id)?;
+
+            // El voto nulo tambien suma el peso del votante para que se compare con los votos a candidatos
+            let peso = self.pesos.get(&votante.id).copied().unwrap_or(1);
+            self.votos_nulos = self.votos_nulos.checked_add(peso).ok_or(Error::Overflow)?;
+            self.votos_nulos_por_cabeza = self
+                .votos_nulos_por_cabeza
+                .checked_add(1)
+                .ok_or(Error::Overflow)?;
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_lista_en_eleccion(
+            &mut self,
+            nro_lista: u64,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea por listas o unas primarias
+            if !matches!(
+                self.modo,
+                ModoEleccion::Listas { .. } | ModoEleccion::Primarias { .. }
+            ) {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Verificar que la lista exista e incrementar sus votos
+            let lista = self
+                .listas
+                .get_mut(nro_lista as usize)
+                .ok_or(Error::ListaNoExiste)?;
+            lista.votos = lista.votos.checked_add(1).ok_or(Error::Overflow)?;
+
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+
+        fn votar_boleta_unica_en_eleccion(
+            &mut self,
+            boleta: Vec<AccountId>,
+            votante: Usuario,
+        ) -> Result<(), Error> {
+            // Verificar que la eleccion sea de boleta unica
+            if self.modo != ModoEleccion::BoletaUnica {
+                return Err(Error::ModoEleccionInvalido);
+            }
+
+            // Verificar que el votante no haya votado ya
+            if self.votantes_que_votaron.iter().any(|v| v.id == votante.id) {
+                return Err(Error::UsuarioYaRegistrado);
+            }
+
+            // Verificar que haya un voto por categoria y que cada candidato compita en la suya antes de sumar ninguno
+            if boleta.len() != self.categorias.len() {
+                return Err(Error::BoletaInvalida);
+            }
+            for (categoria, id_candidato) in self.categorias.iter().zip(boleta.iter()) {
+                match categoria.candidatos.get(id_candidato) {
+                    Some(votos) if votos.checked_add(1).is_some() => {}
+                    Some(_) => return Err(Error::Overflow),
+                    None => return Err(Error::CandidatoNoExiste),
+                }
+            }
+
+            for (categoria, id_candidato) in self.categorias.iter_mut().zip(boleta.iter()) {
+                if let Some(votos) = categoria.candidatos.get_mut(id_candidato) {
+                    *votos += 1;
+                }
+            }
+
+            self.votantes_que_votaron.push(votante);
+            Ok(())
+        }
+    }
+
+    /// Normaliza el nombre de un candidato escrito sacando los espacios de los extremos, dejando un solo espacio entre palabras y pasandolo a minusculas
+    fn normalizar_nombre(nombre: &str) -> String {
+        nombre
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    /// Calcula el compromiso de un voto secreto como el hash blake2 de (eleccion, votante, candidato, sal). Incluir la eleccion y el votante evita que otro copie el compromiso y revele el mismo voto, la sal la elige el votante y la tiene que guardar para poder revelar el voto
+    pub fn calcular_compromiso(
+        id_eleccion: u64,
+        id_votante: AccountId,
+        id_candidato: AccountId,
+        sal: [u8; 32],
+    ) -> [u8; 32] {
+        let mut compromiso = [0u8; 32];
+        ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+            &(id_eleccion, id_votante, id_candidato, sal),
+            &mut compromiso,
+        );
+        compromiso
+    }
+
+    /// Calcula el recibo de un voto como el hash blake2 de (eleccion, numero de voto, tipo, boleta, sal). El numero de voto lo asigna el contrato segun el orden en que se conto, asi dos votos iguales con la misma sal no comparten recibo. El tipo separa las boletas que se codifican igual, por ejemplo "escrito" con el nombre normalizado, "blanco" y "nulo" con la boleta vacia
+    pub fn calcular_recibo<T: scale::Encode>(
+        id_eleccion: u64,
+        nro_voto: u64,
+        tipo: &str,
+        boleta: &T,
+        sal: [u8; 32],
+    ) -> [u8; 32] {
+        let mut recibo = [0u8; 32];
+        ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+            &(id_eleccion, nro_voto, tipo, boleta, sal),
+            &mut recibo,
+        );
+        recibo
+    }
+
+    //----------------------Funciones de fecha---------------------------------------------------------
+
+    impl Fecha {
+        pub fn to_timestamp(&self) -> Result<u64, Error> {
+            let date = NaiveDate::from_ymd_opt(self.anio, self.mes, self.dias)
+                .ok_or(Error::FechaInvalida)?; // Manejo del error para una fecha inválida
+
+            // Arranca a las 00:00:00 del día
+            let datetime = date.and_hms_opt(0, 0, 0).ok_or(Error::FechaInvalida)?;
+
+            let timestamp_secs = datetime.and_utc().timestamp() as u64;
+
+            // Pasar a milisegundos, manejando posible overflow
+            timestamp_secs.checked_mul(1000).ok_or(Error::Overflow)
+        }
+    }
+    //----------------------Structs de admin y eleccion---------------------------------------------------------
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Admin {
+        id: AccountId,
+        nombre: String,
+        email: String,
+        password: String,
+    }
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Eleccion {
+        id: u64,
+        cargo: String,
+        fecha_inicio: u64,
+        fecha_fin: u64,
+        candidatos: BTreeMap<AccountId, u64>, // btree para contar los votos de los candidatos eficientemente
+        candidatos_publicos: Vec<Usuario>, // vector para mostrar los candidatos de una eleccion a los usuarios
+        votantes: Vec<Usuario>,            // vector de votantes registrados en la eleccion
+        votantes_que_votaron: Vec<Usuario>, // vector de votantes que votaron en la eleccion
+        modo: ModoEleccion,                // forma en la que se vota y se cuentan los votos
+        boletas_preferenciales: Vec<Vec<AccountId>>, // boletas con el orden de preferencia de cada votante, solo en elecciones preferenciales
+        boletas_puntaje: Vec<Vec<(AccountId, u32)>>, // boletas con el puntaje que cada votante le dio a cada candidato, solo en elecciones de puntaje
+        listas: Vec<Lista>, // listas de candidatos con sus votos, solo en elecciones por listas
+        votos_en_blanco: u64, // votos validos que no eligen a ningun candidato
+        votos_nulos: u64,   // votos anulados, cuentan para la participacion pero no son validos
+        votos_en_blanco_por_cabeza: u64, // votantes que votaron en blanco sin contar el peso
+        votos_nulos_por_cabeza: u64, // votantes que anularon su voto sin contar el peso
+        segunda_vuelta: Option<u64>, // id de la eleccion creada como segunda vuelta de esta
+        opciones: Vec<(String, u64)>, // opciones con sus votos, solo en consultas populares
+        categorias: Vec<Categoria>, // cargos que se votan juntos, solo en elecciones de boleta unica
+        permite_candidatos_escritos: bool, // si los votantes pueden escribir un candidato no registrado
+        votos_escritos: BTreeMap<String, u64>, // votos de los candidatos escritos con el nombre normalizado
+        pesos: BTreeMap<AccountId, u64>, // peso del voto de cada votante, los que no estan pesan 1
+        votos_por_cabeza: BTreeMap<AccountId, u64>, // votantes que eligieron a cada candidato sin contar el peso
+        creditos_gastados: BTreeMap<AccountId, u64>, // creditos que gastaron los votantes en cada candidato, solo en elecciones cuadraticas
+        boletas_acumulativas: BTreeMap<AccountId, Vec<(AccountId, u32)>>, // como repartio sus votos cada votante, solo en elecciones acumulativas
+        politica_desempate: PoliticaDesempate,
+        decision_desempate: Option<AccountId>, // candidato elegido por el admin cuando la politica es su decision
+        listas_promovidas: bool, // si ya se pasaron las listas ganadoras de estas primarias a la eleccion general
+        quorum: u32, // porcentaje minimo del padron que tiene que votar para que la eleccion sea valida
+        mayoria_requerida: MayoriaRequerida,
+        exige_paridad: bool, // si en las listas se tienen que alternar mujeres y varones
+        distritos: Vec<Distrito>,
+        distrito_votantes: BTreeMap<AccountId, u64>, // numero de distrito de cada votante
+        regla_distrital: ReglaDistrital,
+        fin_revelado: Option<u64>, // Some si el voto es secreto, hasta cuando se pueden revelar los votos
+        compromisos: BTreeMap<AccountId, [u8; 32]>, // compromisos todavia sin revelar
+        recibos: Vec<[u8; 32]>,    // recibos de los votos sumados al conteo
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum PoliticaDesempate {
+        DeclararEmpate,       // el reporte muestra el empate sin ganador
+        PrimeroEnRegistrarse, // gana el empatado que se registro primero en la eleccion
+        DecisionAdmin,        // el admin elige entre los empatados con desempatar
+        SegundaVuelta, // el admin crea una segunda vuelta entre los empatados con crear_segunda_vuelta_por_empate
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum MayoriaRequerida {
+        Simple,     // gana el mas votado
+        Absoluta,   // el ganador necesita mas de la mitad de los votos validos
+        DosTercios, // el ganador necesita al menos dos tercios de los votos validos
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ResultadoPrimeraVuelta {
+        Ganador(AccountId), // el candidato gano en primera vuelta
+        SegundaVuelta(u64), // id de la eleccion de segunda vuelta creada
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ModoEleccion {
+        Mayoria,      // cada votante elige un solo candidato, gana el que tiene mas votos
+        Preferencial, // cada votante ordena a todos los candidatos, se cuenta por segunda vuelta instantanea
+        Aprobacion,   // cada votante aprueba a uno o mas candidatos, cada aprobacion suma un voto
+        Borda, // cada votante ordena a todos los candidatos, cada posicion suma puntos de n-1 a 0
+        // cada votante le da a cada candidato un puntaje entre 0 y maximo, con star el ganador sale de una segunda vuelta entre los dos de mas puntaje
+        Puntaje {
+            maximo: u32,
+            star: bool,
+        },
+        // cada votante ordena a todos los candidatos, se reparten las bancas por voto unico transferible con cuota droop
+        Stv {
+            bancas: u32,
+        },
+        // cada votante elige una lista, las bancas se reparten por d'hondt entre las listas que superan el umbral (porcentaje del padron)
+        Listas {
+            bancas: u32,
+            umbral: u32,
+        },
+        Consulta, // cada votante elige una de las opciones fijas de la pregunta, no hay candidatos
+        BoletaUnica, // cada votante elige un candidato en cada categoria con un solo voto
+        // cada votante tiene creditos y k votos a un candidato cuestan k² creditos
+        Cuadratico {
+            creditos: u64,
+        },
+        // cada votante reparte sus votos entre los candidatos y entran los mas votados
+        Acumulativo {
+            votos_por_votante: u32,
+            bancas: u32,
+            exige_total: bool, // si el votante tiene que usar todos sus votos o puede usar menos
+        },
+        // cada votante elige una lista interna de una agrupacion, la lista ganadora de cada agrupacion pasa a la eleccion general
+        Primarias {
+            eleccion_general: u64,
+        },
+    }
+    impl ModoEleccion {
+        /// Devuelve true si en este modo los votos de los candidatos son el resultado final, solo ahi tiene sentido una politica de desempate. En borda los votos son los puntos totales, en cambio en preferencial, stv o star el primer lugar del conteo no es necesariamente el ganador
+        pub fn admite_desempate(&self) -> bool {
+            matches!(
+                self,
+                ModoEleccion::Mayoria
+                    | ModoEleccion::Borda
+                    | ModoEleccion::Aprobacion
+                    | ModoEleccion::Cuadratico { .. }
+                    | ModoEleccion::Puntaje { star: false, .. }
+            )
+        }
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Lista {
+        agrupacion: String, // en una eleccion por listas es el mismo nombre de la lista
+        nombre: String,
+        candidatos: Vec<AccountId>, // en el orden en que entran a las bancas
+        votos: u64,
+    }
+    impl Lista {
+        pub fn new(nombre: String, candidatos: Vec<AccountId>, votos: u64) -> Self {
+            Self {
+                agrupacion: nombre.clone(),
+                nombre,
+                candidatos,
+                votos,
+            }
+        }
+
+        pub fn agrupacion(&self) -> &String {
+            &self.agrupacion
+        }
+
+        pub fn nombre(&self) -> &String {
+            &self.nombre
+        }
+
+        pub fn candidatos(&self) -> &Vec<AccountId> {
+            &self.candidatos
+        }
+
+        pub fn votos(&self) -> u64 {
+            self.votos
+        }
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Categoria {
+        cargo: String,
+        candidatos: BTreeMap<AccountId, u64>,
+    }
+    impl Categoria {
+        pub fn new(cargo: String, candidatos: BTreeMap<AccountId, u64>) -> Self {
+            Self { cargo, candidatos }
+        }
+
+        pub fn cargo(&self) -> &String {
+            &self.cargo
+        }
+
+        pub fn candidatos(&self) -> &BTreeMap<AccountId, u64> {
+            &self.candidatos
+        }
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Distrito {
+        nombre: String,
+        electores: u32,
+        candidatos: BTreeMap<AccountId, u64>, // votos de cada candidato en el distrito
+    }
+    impl Distrito {
+        pub fn new(nombre: String, electores: u32, candidatos: BTreeMap<AccountId, u64>) -> Self {
+            Self {
+                nombre,
+                electores,
+                candidatos,
+            }
+        }
+
+        pub fn nombre(&self) -> &String {
+            &self.nombre
+        }
+
+        pub fn electores(&self) -> u32 {
+            self.electores
+        }
+
+        pub fn candidatos(&self) -> &BTreeMap<AccountId, u64> {
+            &self.candidatos
+        }
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ReglaDistrital {
+        GanadorSeLlevaTodo, // el mas votado del distrito se lleva todos sus electores
+        Proporcional,       // los electores del distrito se reparten por d'hondt
+    }
+
+    //----------------------Structs de usuarios---------------------------------------------------------
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum RolUsuario {
+        Candidato,
+        Votante,
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Usuario {
+        id: AccountId,
+        nombre: String,
+        email: String,
+        rol: RolUsuario,
+        genero: Option<Genero>, // None hasta que el usuario lo declare
+    }
+    impl Usuario {
+        pub fn new(id: AccountId, nombre: String, email: String, rol: RolUsuario) -> Self {
+            Self {
+                id,
+                nombre,
+                email,
+                rol,
+                genero: None,
+            }
+        }
+    }
+
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Genero {
+        Femenino,
+        Masculino,
+        NoBinario,
+    }
+
+    //----------------------Structs de fecha---------------------------------------------------------
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Fecha {
+        dias: u32,
+        mes: u32,
+        anio: i32,
+    }
+    //----------------------Tests y errores---------------------------------------------------------
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Error {
+        PermisoDenegado,
+        EleccionNoExiste,
+        EleccionAbierta,
+        EleccionNoActiva,
+        UsuarioNoVotante,
+        UsuarioYaRegistrado,
+        AdminNoPuedeRegistrarse,
+        CandidatoNoExiste,
+        UsuarioNoCandidato,
+        FechaInvalida,
+        Overflow,
+        ModoEleccionInvalido,
+        BoletaInvalida,
+        ConfiguracionInvalida,
+        ListaNoExiste,
+        SinVotos,
+        SegundaVueltaYaCreada,
+        OpcionNoExiste,
+        CategoriaNoExiste,
+        CandidatosEscritosNoPermitidos,
+        CreditosInsuficientes,
+        PoliticaDesempateInvalida,
+        SinEmpate,
+        DesempateYaResuelto,
+        ListasYaPromovidas,
+        UsuarioNoRegistrado,
+        ListaSinParidad,
+        GeneroBloqueado,
+        DistritoNoExiste,
+        VotanteSinDistrito,
+        VotoSecreto,
+        CompromisoNoExiste,
+        CompromisoInvalido,
+        ReciboDuplicado,
+    }
+
+    impl core::fmt::Display for Error {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match *self {
+                Error::PermisoDenegado => write!(f, "Permiso denegado"),
+                Error::EleccionNoExiste => write!(f, "La elección no existe"),
+                Error::EleccionAbierta => write!(f, "La elección está abierta"),
+                Error::EleccionNoActiva => write!(f, "La elección no está activa"),
+                Error::UsuarioNoVotante => write!(f, "El usuario no es votante"),
+                Error::UsuarioYaRegistrado => write!(f, "El usuario ya está registrado"),
+                Error::AdminNoPuedeRegistrarse => write!(f, "El admin no puede registrarse"),
+                Error::CandidatoNoExiste => write!(f, "El candidato no existe"),
+                Error::UsuarioNoCandidato => write!(f, "El usuario no es candidato"),
+                Error::FechaInvalida => write!(f, "Fecha inválida"),
+                Error::Overflow => write!(f, "Overflow"),
+                Error::ModoEleccionInvalido => {
+                    write!(f, "La operación no corresponde al modo de la elección")
+                }
+                Error::BoletaInvalida => write!(f, "Boleta inválida"),
+                Error::ConfiguracionInvalida => {
+                    write!(f, "Configuración de la elección inválida")
+                }
+                Error::ListaNoExiste => write!(f, "La lista no existe"),
+                Error::SinVotos => write!(f, "La elección no tiene votos"),
+                Error::SegundaVueltaYaCreada => write!(f, "La segunda vuelta ya fue creada"),
+                Error::OpcionNoExiste => write!(f, "La opción no existe"),
+                Error::CategoriaNoExiste => write!(f, "La categoría no existe"),
+                Error::CandidatosEscritosNoPermitidos => {
+                    write!(f, "La elección no permite candidatos escritos")
+                }
+                Error::CreditosInsuficientes => write!(f, "Créditos insuficientes"),
+                Error::PoliticaDesempateInvalida => {
+                    write!(f, "La política de desempate no permite la operación")
+                }
+                Error::SinEmpate => write!(f, "La elección no tiene empate"),
+                Error::DesempateYaResuelto => write!(f, "El empate ya fue resuelto"),
+                Error::ListasYaPromovidas => write!(f, "Las listas ya fueron promovidas"),
+                Error::UsuarioNoRegistrado => write!(f, "El usuario no está registrado"),
+                Error::ListaSinParidad => write!(f, "La lista no cumple la paridad de género"),
+                Error::GeneroBloqueado => {
+                    write!(f, "El género no se puede cambiar estando en una lista")
+                }
+                Error::DistritoNoExiste => write!(f, "El distrito no existe"),
+                Error::VotanteSinDistrito => {
+                    write!(f, "El votante no tiene distrito asignado")
+                }
+                Error::VotoSecreto => write!(f, "La elección tiene voto secreto"),
+                Error::CompromisoNoExiste => write!(f, "El votante no comprometió su voto"),
+                Error::CompromisoInvalido => {
+                    write!(f, "El voto no coincide con el compromiso")
+                }
+                Error::ReciboDuplicado => write!(f, "El recibo del voto ya existe"),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+        use ink::primitives::AccountId;
+        use ink_env::test::set_block_timestamp;
+        #[test]
+        fn test_fecha_to_timestamp() {
+            let mut fecha = Fecha {
+                dias: 1,
+                mes: 1,
+                anio: 2021,
+            };
+            assert_eq!(fecha.to_timestamp().unwrap(), 1609459200000);
+            fecha.dias = 32;
+            assert_eq!(fecha.to_timestamp().unwrap_err(), Error::FechaInvalida);
+        }
+        #[test]
+        fn test_set_admin_priv() {
+            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+            assert!(sistema
+                .set_admin_priv(
+                    AccountId::from([0x01; 32]),
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                    AccountId::from([0x02; 32])
+                )
+                .is_ok());
+            assert!(sistema
+                .set_admin_priv(
+                    AccountId::from([0x01; 32]),
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    " ".to_string(),
+                    AccountId::from([0x03; 32])
+                )
+                .is_err());
+        }
+
+        #[ink::test]
+        fn test_eleccion_activa() {
+            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+            sistema.crear_eleccion_priv(
+                AccountId::from([0x01; 32]),
+                "cargo".to_string(),
+                Fecha {
+                    dias: 1,
+                    mes: 7,
+                    anio: 2024,
+                },
+                Fecha {
+                    dias: 3,
+                    mes: 7,
+                    anio: 2024,
+                },
+            );
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1719878400000);
+
+            assert!(sistema.eleccion_activa(0).is_ok());
+            assert_eq!(sistema.eleccion_activa(0).unwrap(), true);
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1800000000000);
+
+            assert!(sistema.eleccion_activa(0).is_ok());
+            assert_eq!(sistema.eleccion_activa(0).unwrap(), false);
+            assert!(sistema.eleccion_activa(1).is_err());
+        }
+        #[ink::test]
+        fn test_eleccion_cerrada() {
+            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+            sistema.crear_eleccion_priv(
+                AccountId::from([0x01; 32]),
+                "cargo".to_string(),
+                Fecha {
+                    dias: 1,
+                    mes: 1,
+                    anio: 2023,
+                },
+                Fecha {
+                    dias: 1,
+                    mes: 2,
+                    anio: 2023,
+                },
+            );
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1719878400000);
+
+            assert!(sistema.eleccion_cerrada(0).is_ok());
+            assert_eq!(sistema.eleccion_cerrada(0).unwrap(), true);
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(17198784000);
+
+            assert!(sistema.eleccion_cerrada(0).is_ok());
+            assert_eq!(sistema.eleccion_cerrada(0).unwrap(), false);
+            assert!(sistema.eleccion_cerrada(1).is_err());
+        }
+        #[ink::test]
+        fn test_eleccion_no_abierta() {
+            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+            sistema.crear_eleccion_priv(
+                AccountId::from([0x01; 32]),
+                "cargo".to_string(),
+                Fecha {
+                    dias: 1,
+                    mes: 1,
+                    anio: 2023,
+                },
+                Fecha {
+                    dias: 1,
+                    mes: 2,
+                    anio: 2023,
+                },
+            );
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1719878400000);
+
+            assert!(sistema.eleccion_no_abierta(0).is_ok());
+            assert_eq!(sistema.eleccion_no_abierta(0).unwrap(), false);
+
+            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(17198784000);
+
+            assert!(sistema.eleccion_no_abierta(0).is_ok());
+            assert_eq!(sistema.eleccion_no_abierta(0).unwrap(), true);
+            assert!(sistema.eleccion_no_abierta(1).is_err());
+        }
+        #[ink::test]
+        fn test_crear_eleccion_priv() {
+            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+            assert!(sistema
+                .crear_eleccion_priv(
+                    AccountId::from([0x01; 32]),
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2021,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2022,
+                    }
+                )
+                .is_ok());
+            assert!(sistema
+                .crear_eleccion_priv(
+                    AccountId::from([0x02; 32]),
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2021,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2022,
+                    }
+                )
+                .is_err());
+        }
+
+        #[ink::test]
+        fn test_registar_usuario_priv() {
+            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+
+            assert!(sistema
+                .registrar_usuario_priv(
+                    AccountId::from([0x02; 32]),
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante
+                )
+                .is_ok());
+            assert!(sistema
+                .registrar_usuario_priv(
+                    AccountId::from([0x02; 32]),
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante
+                )
+                .is_err());
+            assert!(sistema
+                .registrar_usuario_priv(
+                    AccountId::from([0x01; 32]),
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante
+                )
+                .is_err());
+        }
+        #[ink::test]
+        fn test_registrar_votante_en_eleccion_priv() {
+            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+            sistema
+                .registrar_usuario_priv(
+                    AccountId::from([0x02; 32]),
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    AccountId::from([0x01; 32]),
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2021,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2022,
+                    },
+                )
+                .unwrap();
+            assert!(sistema
+                .registrar_votante_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
+                .is_ok());
+            assert!(sistema
+                .registrar_votante_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
+                .is_err());
+
+            assert!(sistema
+                .registrar_votante_en_eleccion_priv(AccountId::from([0x02; 32]), 1)
+                .is_err(),);
+            assert!(sistema
+                .registrar_votante_en_eleccion_priv(AccountId::from([0x01; 32]), 0)
+                .is_err());
+
+            // setear timestamp par que la eleccion este abierta y no se pueda registrar votante
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+            assert!(sistema
+                .registrar_votante_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
+                .is_err());
+        }
+        #[ink::test]
+        fn test_registrar_candidato_en_eleccion_priv() {
+            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+            sistema.registrar_usuario_priv(
+                AccountId::from([0x02; 32]),
+                "Agustin".to_string(),
+                " ".to_string(),
+                RolUsuario::Candidato,
+            );
+            sistema
+                .crear_eleccion_priv(
+                    AccountId::from([0x01; 32]),
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2021,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2022,
+                    },
+                )
+                .unwrap();
+
+            assert!(sistema
+                .registrar_candidato_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
+                .is_ok());
+            assert!(sistema
+                .registrar_candidato_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
+                .is_err());
+            assert!(sistema
+                .registrar_candidato_en_eleccion_priv(AccountId::from([0x01; 32]), 0)
+                .is_err());
+            assert!(sistema
+                .registrar_candidato_en_eleccion_priv(AccountId::from([0x02; 32]), 1)
+                .is_err());
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+            assert!(sistema
+                .registrar_candidato_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
+                .is_err());
+        }
+        #[ink::test]
+        fn test_votar_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato = AccountId::from([0x03; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema.registrar_usuario_priv(
+                id_votante,
+                "Agustin".to_string(),
+                " ".to_string(),
+                RolUsuario::Votante,
+            );
+            sistema.registrar_usuario_priv(
+                id_candidato,
+                "".to_string(),
+                "".to_string(),
+                RolUsuario::Candidato,
+            );
+
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+
+            sistema
+                .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                .unwrap();
+
+            assert!(sistema
+                .votar_priv(id_votante, 0, id_candidato, [0x00; 32])
+                .is_err());
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert!(sistema
+                .votar_priv(id_votante, 0, AccountId::from([0x09; 32]), [0x00; 32])
+                .is_err());
+
+            assert!(sistema
+                .votar_priv(id_admin, 0, id_candidato, [0x00; 32])
+                .is_err());
+            assert!(sistema
+                .votar_priv(id_votante, 0, id_candidato, [0x00; 32])
+                .is_ok());
+            assert!(sistema
+                .votar_priv(id_votante, 0, id_candidato, [0x00; 32])
+                .is_err());
+        }
+
+        #[ink::test]
+        fn test_set_modo_eleccion_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+
+            assert!(sistema
+                .set_modo_eleccion_priv(AccountId::from([0x02; 32]), 0, ModoEleccion::Preferencial)
+                .is_err());
+            assert!(sistema
+                .set_modo_eleccion_priv(id_admin, 1, ModoEleccion::Preferencial)
+                .is_err());
+            assert!(sistema
+                .set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Preferencial)
+                .is_ok());
+            assert_eq!(sistema.elecciones[0].modo, ModoEleccion::Preferencial);
+            // stv necesita al menos dos bancas y el puntaje maximo no puede ser 0
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Stv { bancas: 1 }),
+                Err(Error::ConfiguracionInvalida)
+            );
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(
+                    id_admin,
+                    0,
+                    ModoEleccion::Puntaje {
+                        maximo: 0,
+                        star: false
+                    }
+                ),
+                Err(Error::ConfiguracionInvalida)
+            );
+            assert!(sistema
+                .set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Stv { bancas: 5 })
+                .is_ok());
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Mayoria),
+                Err(Error::EleccionAbierta)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_preferencial_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato1 = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            let id_contrato = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            for id_candidato in [id_candidato1, id_candidato2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+                sistema
+                    .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                    .unwrap();
+            }
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+
+            // una eleccion de mayoria no tiene boletas preferenciales
+            assert_eq!(
+                sistema.get_boletas_preferenciales_priv(0, id_contrato),
+                Err(Error::ModoEleccionInvalido)
+            );
+            sistema
+                .set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Preferencial)
+                .unwrap();
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            // en una eleccion preferencial no se puede votar a un solo candidato
+            assert_eq!(
+                sistema.votar_priv(id_votante, 0, id_candidato1, [0x00; 32]),
+                Err(Error::ModoEleccionInvalido)
+            );
+            // la boleta tiene que ordenar a todos los candidatos sin repetir
+            assert_eq!(
+                sistema.votar_preferencial_priv(id_votante, 0, vec![id_candidato1], [0x00; 32]),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_preferencial_priv(
+                    id_votante,
+                    0,
+                    vec![id_candidato1, id_candidato1],
+                    [0x00; 32]
+                ),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_preferencial_priv(
+                    id_votante,
+                    0,
+                    vec![id_candidato1, AccountId::from([0x09; 32])],
+                    [0x00; 32]
+                ),
+                Err(Error::CandidatoNoExiste)
+            );
+            assert!(sistema
+                .votar_preferencial_priv(
+                    id_admin,
+                    0,
+                    vec![id_candidato2, id_candidato1],
+                    [0x00; 32]
+                )
+                .is_err());
+            assert!(sistema
+                .votar_preferencial_priv(
+                    id_votante,
+                    0,
+                    vec![id_candidato2, id_candidato1],
+                    [0x00; 32]
+                )
+                .is_ok());
+            assert_eq!(
+                sistema.votar_preferencial_priv(
+                    id_votante,
+                    0,
+                    vec![id_candidato2, id_candidato1],
+                    [0x00; 32]
+                ),
+                Err(Error::UsuarioYaRegistrado)
+            );
+
+            // la primera preferencia se refleja en el conteo de votos
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato2),
+                Some(&1)
+            );
+            assert_eq!(
+                sistema
+                    .get_boletas_preferenciales_priv(0, id_contrato)
+                    .unwrap(),
+                vec![vec![id_candidato2, id_candidato1]]
+            );
+            assert!(sistema
+                .get_boletas_preferenciales_priv(0, id_votante)
+                .is_err());
+        }
+
+        #[ink::test]
+        fn test_votar_borda_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato1 = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            let id_candidato3 = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            for id_candidato in [id_candidato1, id_candidato2, id_candidato3] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+                sistema
+                    .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                    .unwrap();
+            }
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+            sistema
+                .set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Borda)
+                .unwrap();
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert!(sistema
+                .votar_preferencial_priv(
+                    id_votante,
+                    0,
+                    vec![id_candidato2, id_candidato3, id_candidato1],
+                    [0x00; 32]
+                )
+                .is_ok());
+
+            // con 3 candidatos el primero suma 2 puntos, el segundo 1 y el ultimo 0
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato2),
+                Some(&2)
+            );
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato3),
+                Some(&1)
+            );
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato1),
+                Some(&0)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_aprobacion_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato1 = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            let id_candidato3 = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            for id_candidato in [id_candidato1, id_candidato2, id_candidato3] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+                sistema
+                    .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                    .unwrap();
+            }
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+            sistema
+                .set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Aprobacion)
+                .unwrap();
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert_eq!(
+                sistema.votar_priv(id_votante, 0, id_candidato1, [0x00; 32]),
+                Err(Error::ModoEleccionInvalido)
+            );
+            assert_eq!(
+                sistema.votar_aprobacion_priv(
+                    id_votante,
+                    0,
+                    vec![id_candidato1, id_candidato1],
+                    [0x00; 32]
+                ),
+                Err(Error::CandidatoNoExiste)
+            );
+            assert_eq!(
+                sistema.votar_aprobacion_priv(
+                    id_votante,
+                    0,
+                    vec![id_candidato1, AccountId::from([0x09; 32])],
+                    [0x00; 32]
+                ),
+                Err(Error::CandidatoNoExiste)
+            );
+            // una boleta rechazada no suma votos
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato1),
+                Some(&0)
+            );
+
+            assert!(sistema
+                .votar_aprobacion_priv(
+                    id_votante,
+                    0,
+                    vec![id_candidato1, id_candidato3],
+                    [0x00; 32]
+                )
+                .is_ok());
+            assert_eq!(
+                sistema.votar_aprobacion_priv(id_votante, 0, vec![id_candidato2], [0x00; 32]),
+                Err(Error::UsuarioYaRegistrado)
+            );
+
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato1),
+                Some(&1)
+            );
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato2),
+                Some(&0)
+            );
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato3),
+                Some(&1)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_puntaje_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato1 = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            let id_contrato = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            for id_candidato in [id_candidato1, id_candidato2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+                sistema
+                    .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                    .unwrap();
+            }
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+            let modo = ModoEleccion::Puntaje {
+                maximo: 5,
+                star: true,
+            };
+            sistema
+                .set_modo_eleccion_priv(id_admin, 0, modo.clone())
+                .unwrap();
+            assert_eq!(sistema.get_modo_eleccion_priv(0, id_contrato), Ok(modo));
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            // la boleta tiene que puntuar a todos los candidatos sin pasarse del maximo
+            assert_eq!(
+                sistema.votar_puntaje_priv(id_votante, 0, vec![(id_candidato1, 5)], [0x00; 32]),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_puntaje_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato1, 6), (id_candidato2, 0)],
+                    [0x00; 32]
+                ),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_puntaje_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato1, 5), (id_candidato1, 0)],
+                    [0x00; 32]
+                ),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_puntaje_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato1, 5), (AccountId::from([0x09; 32]), 0)],
+                    [0x00; 32]
+                ),
+                Err(Error::CandidatoNoExiste)
+            );
+            assert!(sistema
+                .votar_puntaje_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato1, 5), (id_candidato2, 3)],
+                    [0x00; 32]
+                )
+                .is_ok());
+            assert_eq!(
+                sistema.votar_puntaje_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato1, 5), (id_candidato2, 3)],
+                    [0x00; 32]
+                ),
+                Err(Error::UsuarioYaRegistrado)
+            );
+
+            // el puntaje se suma al conteo de votos de cada candidato
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato1),
+                Some(&5)
+            );
+            assert_eq!(
+                sistema.elecciones[0].candidatos.get(&id_candidato2),
+                Some(&3)
+            );
+            assert_eq!(
+                sistema.get_boletas_puntaje_priv(0, id_contrato).unwrap(),
+                vec![vec![(id_candidato1, 5), (id_candidato2, 3)]]
+            );
+            assert!(sistema.get_boletas_puntaje_priv(0, id_votante).is_err());
+        }
+
+        #[ink::test]
+        fn test_registrar_lista_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato1 = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            for id_candidato in [id_candidato1, id_candidato2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+            }
+
+            // en una eleccion de mayoria no hay listas
+            assert_eq!(
+                sistema.registrar_lista_priv(id_admin, 0, "A".to_string(), vec![id_candidato1]),
+                Err(Error::ModoEleccionInvalido)
+            );
+            sistema
+                .set_modo_eleccion_priv(
+                    id_admin,
+                    0,
+                    ModoEleccion::Listas {
+                        bancas: 3,
+                        umbral: 3,
+                    },
+                )
+                .unwrap();
+
+            assert!(sistema
+                .registrar_lista_priv(id_votante, 0, "A".to_string(), vec![id_candidato1])
+                .is_err());
+            assert_eq!(
+                sistema.registrar_lista_priv(id_admin, 0, "A".to_string(), Vec::new()),
+                Err(Error::ConfiguracionInvalida)
+            );
+            assert_eq!(
+                sistema.registrar_lista_priv(id_admin, 0, "A".to_string(), vec![id_votante]),
+                Err(Error::UsuarioNoCandidato)
+            );
+            assert_eq!(
+                sistema.registrar_lista_priv(
+                    id_admin,
+                    0,
+                    "A".to_string(),
+                    vec![id_candidato1, id_candidato1]
+                ),
+                Err(Error::UsuarioYaRegistrado)
+            );
+            assert!(sistema
+                .registrar_lista_priv(id_admin, 0, "A".to_string(), vec![id_candidato1])
+                .is_ok());
+            // no se puede repetir el nombre ni un candidato de otra lista
+            assert_eq!(
+                sistema.registrar_lista_priv(id_admin, 0, "A".to_string(), vec![id_candidato2]),
+                Err(Error::ConfiguracionInvalida)
+            );
+            assert_eq!(
+                sistema.registrar_lista_priv(
+                    id_admin,
+                    0,
+                    "B".to_string(),
+                    vec![id_candidato2, id_candidato1]
+                ),
+                Err(Error::UsuarioYaRegistrado)
+            );
+            assert!(sistema
+                .registrar_lista_priv(id_admin, 0, "B".to_string(), vec![id_candidato2])
+                .is_ok());
+            assert_eq!(sistema.mostrar_candidatos(0).unwrap().len(), 2);
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+            assert_eq!(
+                sistema.registrar_lista_priv(id_admin, 0, "C".to_string(), vec![id_candidato2]),
+                Err(Error::EleccionAbierta)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_lista_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato = AccountId::from([0x03; 32]);
+            let id_contrato = AccountId::from([0x04; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_candidato,
+                    "".to_string(),
+                    "".to_string(),
+                    RolUsuario::Candidato,
+                )
+                .unwrap();
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+            sistema
+                .set_modo_eleccion_priv(
+                    id_admin,
+                    0,
+                    ModoEleccion::Listas {
+                        bancas: 3,
+                        umbral: 3,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_lista_priv(id_admin, 0, "A".to_string(), vec![id_candidato])
+                .unwrap();
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert_eq!(
+                sistema.votar_priv(id_votante, 0, id_candidato, [0x00; 32]),
+                Err(Error::ModoEleccionInvalido)
+            );
+            assert_eq!(
+                sistema.votar_lista_priv(id_votante, 0, 1, [0x00; 32]),
+                Err(Error::ListaNoExiste)
+            );
+            assert!(sistema
+                .votar_lista_priv(id_admin, 0, 0, [0x00; 32])
+                .is_err());
+            assert!(sistema
+                .votar_lista_priv(id_votante, 0, 0, [0x00; 32])
+                .is_ok());
+            assert_eq!(
+                sistema.votar_lista_priv(id_votante, 0, 0, [0x00; 32]),
+                Err(Error::UsuarioYaRegistrado)
+            );
+
+            assert_eq!(
+                sistema.get_listas_priv(0, id_contrato).unwrap(),
+                vec![Lista::new("A".to_string(), vec![id_candidato], 1)]
+            );
+            assert!(sistema.get_listas_priv(0, id_votante).is_err());
+        }
+
+        #[ink::test]
+        fn test_votar_en_blanco_y_nulo_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante1 = AccountId::from([0x02; 32]);
+            let id_votante2 = AccountId::from([0x03; 32]);
+            let id_contrato = AccountId::from([0x04; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "cargo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            for id_votante in [id_votante1, id_votante2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_votante,
+                        "Agustin".to_string(),
+                        " ".to_string(),
+                        RolUsuario::Votante,
+                    )
+                    .unwrap();
+                sistema
+                    .registrar_votante_en_eleccion_priv(id_votante, 0)
+                    .unwrap();
+            }
+
+            assert!(sistema
+                .votar_en_blanco_priv(id_votante1, 0, [0x00; 32])
+                .is_err());
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert!(sistema
+                .votar_en_blanco_priv(id_admin, 0, [0x00; 32])
+                .is_err());
+            assert!(sistema
+                .votar_en_blanco_priv(id_votante1, 0, [0x00; 32])
+                .is_ok());
+            assert!(sistema.votar_nulo_priv(id_votante1, 0, [0x00; 32]).is_err());
+            assert!(sistema.votar_nulo_priv(id_votante2, 0, [0x00; 32]).is_ok());
+            assert!(sistema
+                .votar_en_blanco_priv(id_votante2, 0, [0x00; 32])
+                .is_err());
+
+            // los dos quedan registrados como que votaron
+            assert_eq!(
+                sistema
+                    .get_votantes_que_votaron_priv(0, id_contrato)
+                    .unwrap()
+                    .len(),
+                2
+            );
+            assert_eq!(sistema.get_votos_en_blanco_priv(0, id_contrato), Ok(1));
+            assert_eq!(sistema.get_votos_nulos_priv(0, id_contrato), Ok(1));
+            assert!(sistema.get_votos_en_blanco_priv(0, id_votante1).is_err());
+            assert!(sistema.get_votos_nulos_priv(1, id_contrato).is_err());
+        }
+
+        #[ink::test]
+        fn test_evaluar_ballotage_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato1 = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            let id_candidato3 = AccountId::from([0x05; 32]);
+            let fecha_inicio_ballotage = Fecha {
+                dias: 10,
+                mes: 1,
+                anio: 2025,
+            };
+            let fecha_fin_ballotage = Fecha {
+                dias: 11,
+                mes: 1,
+                anio: 2025,
+            };
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "Presidente".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+            for id_candidato in [id_candidato1, id_candidato2, id_candidato3] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+                sistema
+                    .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                    .unwrap();
+            }
+
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage.clone()
+                ),
+                Err(Error::EleccionAbierta)
+            );
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1800000000000);
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage.clone()
+                ),
+                Err(Error::SinVotos)
+            );
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_votante,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage.clone()
+                ),
+                Err(Error::PermisoDenegado)
+            );
+
+            // con mas del 45% gana en primera vuelta
+            let candidatos = &mut sistema.elecciones[0].candidatos;
+            candidatos.insert(id_candidato1, 46);
+            candidatos.insert(id_candidato2, 30);
+            candidatos.insert(id_candidato3, 24);
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage.clone()
+                ),
+                Ok(ResultadoPrimeraVuelta::Ganador(id_candidato1))
+            );
+
+            // con 40% o mas y mas de 10 puntos de diferencia tambien gana
+            let candidatos = &mut sistema.elecciones[0].candidatos;
+            candidatos.insert(id_candidato1, 41);
+            candidatos.insert(id_candidato2, 30);
+            candidatos.insert(id_candidato3, 29);
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage.clone()
+                ),
+                Ok(ResultadoPrimeraVuelta::Ganador(id_candidato1))
+            );
+
+            // con 10 puntos justos de diferencia hay segunda vuelta entre los dos primeros
+            let candidatos = &mut sistema.elecciones[0].candidatos;
+            candidatos.insert(id_candidato1, 41);
+            candidatos.insert(id_candidato2, 31);
+            candidatos.insert(id_candidato3, 28);
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    Fecha {
+                        dias: 1,
+                        mes: 12,
+                        anio: 2024,
+                    },
+                    fecha_fin_ballotage.clone()
+                ),
+                Err(Error::FechaInvalida)
+            );
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage.clone()
+                ),
+                Ok(ResultadoPrimeraVuelta::SegundaVuelta(1))
+            );
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage.clone()
+                ),
+                Err(Error::SegundaVueltaYaCreada)
+            );
+
+            // la segunda vuelta reusa el cargo y los votantes registrados y solo tiene a los dos finalistas
+            let segunda_vuelta = &sistema.elecciones[1];
+            assert_eq!(segunda_vuelta.cargo, "Presidente");
+            assert_eq!(
+                segunda_vuelta.fecha_inicio,
+                fecha_inicio_ballotage.to_timestamp().unwrap()
+            );
+            assert_eq!(segunda_vuelta.votantes, sistema.elecciones[0].votantes);
+            assert_eq!(
+                segunda_vuelta.candidatos.keys().collect::<Vec<_>>(),
+                vec![&id_candidato1, &id_candidato2]
+            );
+            assert_eq!(segunda_vuelta.candidatos_publicos.len(), 2);
+
+            // con un empate en el primer lugar nadie gana aunque los dos superen el 45%
+            sistema.elecciones[0].segunda_vuelta = None;
+            let candidatos = &mut sistema.elecciones[0].candidatos;
+            candidatos.insert(id_candidato1, 46);
+            candidatos.insert(id_candidato2, 46);
+            candidatos.insert(id_candidato3, 8);
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage.clone()
+                ),
+                Ok(ResultadoPrimeraVuelta::SegundaVuelta(2))
+            );
+            assert_eq!(sistema.elecciones[2].candidatos.len(), 2);
+
+            // si empatan en el segundo lugar pasan todos los empatados
+            sistema.elecciones[0].segunda_vuelta = None;
+            let candidatos = &mut sistema.elecciones[0].candidatos;
+            candidatos.insert(id_candidato1, 40);
+            candidatos.insert(id_candidato2, 30);
+            candidatos.insert(id_candidato3, 30);
+            assert_eq!(
+                sistema.evaluar_ballotage_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_ballotage.clone(),
+                    fecha_fin_ballotage
+                ),
+                Ok(ResultadoPrimeraVuelta::SegundaVuelta(3))
+            );
+            assert_eq!(sistema.elecciones[3].candidatos.len(), 3);
+        }
+
+        #[ink::test]
+        fn test_crear_consulta_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_contrato = AccountId::from([0x02; 32]);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            let fecha_inicio = Fecha {
+                dias: 1,
+                mes: 1,
+                anio: 2024,
+            };
+            let fecha_fin = Fecha {
+                dias: 1,
+                mes: 1,
+                anio: 2025,
+            };
+
+            assert!(sistema
+                .crear_consulta_priv(
+                    AccountId::from([0x03; 32]),
+                    "¿Aprueba la reforma?".to_string(),
+                    Vec::new(),
+                    fecha_inicio.clone(),
+                    fecha_fin.clone()
+                )
+                .is_err());
+            assert_eq!(
+                sistema.crear_consulta_priv(
+                    id_admin,
+                    "¿Aprueba la reforma?".to_string(),
+                    vec!["Sí".to_string(), "Sí".to_string()],
+                    fecha_inicio.clone(),
+                    fecha_fin.clone()
+                ),
+                Err(Error::ConfiguracionInvalida)
+            );
+
+            // sin opciones se usan Sí, No y Abstención
+            assert!(sistema
+                .crear_consulta_priv(
+                    id_admin,
+                    "¿Aprueba la reforma?".to_string(),
+                    Vec::new(),
+                    fecha_inicio.clone(),
+                    fecha_fin.clone()
+                )
+                .is_ok());
+            assert_eq!(
+                sistema.get_opciones_priv(0, id_contrato).unwrap(),
+                vec![
+                    ("Sí".to_string(), 0),
+                    ("No".to_string(), 0),
+                    ("Abstención".to_string(), 0)
+                ]
+            );
+            assert!(sistema
+                .crear_consulta_priv(
+                    id_admin,
+                    "¿Qué día se juntan?".to_string(),
+                    vec!["Lunes".to_string(), "Martes".to_string()],
+                    fecha_inicio.clone(),
+                    fecha_fin.clone()
+                )
+                .is_ok());
+            assert_eq!(sistema.get_opciones_priv(1, id_contrato).unwrap().len(), 2);
+            assert_eq!(
+                sistema.get_cargo_priv(1, id_contrato).unwrap(),
+                "¿Qué día se juntan?"
+            );
+
+            // una consulta no puede cambiar de modo
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Mayoria),
+                Err(Error::ModoEleccionInvalido)
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_opcion_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_contrato = AccountId::from([0x03; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_consulta_priv(
+                    id_admin,
+                    "¿Aprueba la reforma?".to_string(),
+                    Vec::new(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+
+            // en una consulta no se registran candidatos
+            let id_candidato = AccountId::from([0x04; 32]);
+            sistema
+                .registrar_usuario_priv(
+                    id_candidato,
+                    "".to_string(),
+                    "".to_string(),
+                    RolUsuario::Candidato,
+                )
+                .unwrap();
+            assert_eq!(
+                sistema.registrar_candidato_en_eleccion_priv(id_candidato, 0),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            assert!(sistema
+                .votar_opcion_priv(id_votante, 0, 0, [0x00; 32])
+                .is_err());
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert_eq!(
+                sistema.votar_opcion_priv(id_votante, 0, 3, [0x00; 32]),
+                Err(Error::OpcionNoExiste)
+            );
+            assert!(sistema
+                .votar_opcion_priv(id_admin, 0, 0, [0x00; 32])
+                .is_err());
+            assert!(sistema
+                .votar_opcion_priv(id_votante, 0, 1, [0x00; 32])
+                .is_ok());
+            assert_eq!(
+                sistema.votar_opcion_priv(id_votante, 0, 1, [0x00; 32]),
+                Err(Error::UsuarioYaRegistrado)
+            );
+            assert_eq!(
+                sistema.get_opciones_priv(0, id_contrato).unwrap()[1],
+                ("No".to_string(), 1)
+            );
+            assert!(sistema.get_opciones_priv(0, id_votante).is_err());
+        }
+
+        #[ink::test]
+        fn test_votar_boleta_unica_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_presidente = AccountId::from([0x03; 32]);
+            let id_gobernador = AccountId::from([0x04; 32]);
+            let id_contrato = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "generales".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
+                    "Agustin".to_string(),
+                    " ".to_string(),
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_presidente,
+                    "".to_string(),
+                    "".to_string(),
+                    RolUsuario::Candidato,
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_gobernador,
+                    "".to_string(),
+                    "".to_string(),
+                    RolUsuario::Candidato,
+                )
+                .unwrap();
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+
+            // sin el modo de boleta unica no se pueden agregar categorias
+            assert_eq!(
+                sistema.agregar_categoria_priv(id_admin, 0, "Presidente".to_string()),
+                Err(Error::ModoEleccionInvalido)
+            );
+            sistema
+                .set_modo_eleccion_priv(id_admin, 0, ModoEleccion::BoletaUnica)
+                .unwrap();
+            assert!(sistema
+                .agregar_categoria_priv(id_votante, 0, "Presidente".to_string())
+                .is_err());
+            assert!(sistema
+                .agregar_categoria_priv(id_admin, 0, "Presidente".to_string())
+                .is_ok());
+            assert_eq!(
+                sistema.agregar_categoria_priv(id_admin, 0, "Presidente".to_string()),
+                Err(Error::ConfiguracionInvalida)
+            );
+            assert!(sistema
+                .agregar_categoria_priv(id_admin, 0, "Gobernador".to_string())
+                .is_ok());
+
+            assert_eq!(
+                sistema.registrar_candidato_en_categoria_priv(id_votante, 0, 0),
+                Err(Error::UsuarioNoCandidato)
+            );
+            assert_eq!(
+                sistema.registrar_candidato_en_categoria_priv(id_presidente, 0, 2),
+                Err(Error::CategoriaNoExiste)
+            );
+            assert!(sistema
+                .registrar_candidato_en_categoria_priv(id_presidente, 0, 0)
+                .is_ok());
+            assert_eq!(
+                sistema.registrar_candidato_en_categoria_priv(id_presidente, 0, 1),
+                Err(Error::UsuarioYaRegistrado)
+            );
+            assert!(sistema
+                .registrar_candidato_en_categoria_priv(id_gobernador, 0, 1)
+                .is_ok());
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert_eq!(
+                sistema.votar_priv(id_votante, 0, id_presidente, [0x00; 32]),
+                Err(Error::ModoEleccionInvalido)
+            );
+            assert_eq!(
+                sistema.votar_boleta_unica_priv(id_votante, 0, vec![id_presidente], [0x00; 32]),
+                Err(Error::BoletaInvalida)
+            );
+            // el gobernador no compite para presidente, no se registra ningun voto
+            assert_eq!(
+                sistema.votar_boleta_unica_priv(
+                    id_votante,
+                    0,
+                    vec![id_presidente, id_presidente],
+                    [0x00; 32]
+                ),
+                Err(Error::CandidatoNoExiste)
+            );
+            assert_eq!(
+                sistema.get_categorias_priv(0, id_contrato).unwrap()[0].candidatos()
+                    [&id_presidente],
+                0
+            );
+            assert!(sistema
+                .votar_boleta_unica_priv(
+                    id_admin,
+                    0,
+                    vec![id_presidente, id_gobernador],
+                    [0x00; 32]
+                )
+                .is_err());
+            assert!(sistema
+                .votar_boleta_unica_priv(
+                    id_votante,
+                    0,
+                    vec![id_presidente, id_gobernador],
+                    [0x00; 32]
+                )
+                .is_ok());
+            assert_eq!(
+                sistema.votar_boleta_unica_priv(
+                    id_votante,
+                    0,
+                    vec![id_presidente, id_gobernador],
+                    [0x00; 32]
+                ),
+                Err(Error::UsuarioYaRegistrado)
+            );
+
+            let categorias = sistema.get_categorias_priv(0, id_contrato).unwrap();
+            assert_eq!(categorias[0].cargo(), "Presidente");
+            assert_eq!(categorias[0].candidatos()[&id_presidente], 1);
+            assert_eq!(categorias[1].candidatos()[&id_gobernador], 1);
+            assert!(sistema.get_categorias_priv(0, id_votante).is_err());
+        }
+
+        #[ink::test]
+        fn test_votar_candidato_escrito_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_votante2 = AccountId::from([0x03; 32]);
+            let id_contrato = AccountId::from([0x04; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "presidente del club".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            for id in [id_votante, id_votante2] {
+                sistema
+                    .registrar_usuario_priv(id, "".to_string(), "".to_string(), RolUsuario::Votante)
+                    .unwrap();
+                sistema.registrar_votante_en_eleccion_priv(id, 0).unwrap();
+            }
+
+            assert!(sistema
+                .set_candidatos_escritos_priv(id_votante, 0, true)
+                .is_err());
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            // por defecto no se permiten candidatos escritos y una vez abierta no se puede cambiar
+            assert_eq!(
+                sistema.votar_candidato_escrito_priv(
+                    id_votante,
+                    0,
+                    "Juan Perez".to_string(),
+                    [0x00; 32]
+                ),
+                Err(Error::CandidatosEscritosNoPermitidos)
+            );
+            assert_eq!(
+                sistema.set_candidatos_escritos_priv(id_admin, 0, true),
+                Err(Error::EleccionAbierta)
+            );
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            assert!(sistema
+                .set_candidatos_escritos_priv(id_admin, 0, true)
+                .is_ok());
+            sistema
+                .set_peso_votante_priv(id_admin, 0, id_votante2, 3)
+                .unwrap();
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert_eq!(
+                sistema.votar_candidato_escrito_priv(id_votante, 0, "   ".to_string(), [0x00; 32]),
+                Err(Error::BoletaInvalida)
+            );
+            assert!(sistema
+                .votar_candidato_escrito_priv(id_admin, 0, "Juan Perez".to_string(), [0x00; 32])
+                .is_err());
+            assert!(sistema
+                .votar_candidato_escrito_priv(id_votante, 0, "Juan Perez".to_string(), [0x00; 32])
+                .is_ok());
+            assert_eq!(
+                sistema.votar_candidato_escrito_priv(
+                    id_votante,
+                    0,
+                    "Juan Perez".to_string(),
+                    [0x00; 32]
+                ),
+                Err(Error::UsuarioYaRegistrado)
+            );
+            assert!(sistema
+                .votar_candidato_escrito_priv(
+                    id_votante2,
+                    0,
+                    "  juan   PEREZ ".to_string(),
+                    [0x00; 32]
+                )
+                .is_ok());
+
+            let votos_escritos = sistema.get_votos_escritos_priv(0, id_contrato).unwrap();
+            assert_eq!(votos_escritos.len(), 1);
+            // el segundo voto suma el peso 3 del votante
+            assert_eq!(votos_escritos["juan perez"], 4);
+            assert!(sistema.get_votos_escritos_priv(0, id_votante).is_err());
+        }
+
+        #[ink::test]
+        fn test_votar_con_peso_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_votante2 = AccountId::from([0x03; 32]);
+            let id_candidato = AccountId::from([0x04; 32]);
+            let id_contrato = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "asamblea".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            for id in [id_votante, id_votante2] {
+                sistema
+                    .registrar_usuario_priv(id, "".to_string(), "".to_string(), RolUsuario::Votante)
+                    .unwrap();
+            }
+            sistema
+                .registrar_usuario_priv(
+                    id_candidato,
+                    "".to_string(),
+                    "".to_string(),
+                    RolUsuario::Candidato,
+                )
+                .unwrap();
+            sistema
+                .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                .unwrap();
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+
+            assert!(sistema
+                .set_peso_votante_priv(id_votante, 0, id_votante, 10)
+                .is_err());
+            assert_eq!(
+                sistema.set_peso_votante_priv(id_admin, 0, id_votante2, 10),
+                Err(Error::UsuarioNoVotante)
+            );
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante2, 0)
+                .unwrap();
+            assert_eq!(
+                sistema.set_peso_votante_priv(id_admin, 0, id_votante, 0),
+                Err(Error::ConfiguracionInvalida)
+            );
+            assert!(sistema
+                .set_peso_votante_priv(id_admin, 0, id_votante, 10)
+                .is_ok());
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert_eq!(
+                sistema.set_peso_votante_priv(id_admin, 0, id_votante, 20),
+                Err(Error::EleccionAbierta)
+            );
+
+            // el votante con peso suma 10 y el que no tiene peso asignado suma 1
+            sistema
+                .votar_priv(id_votante, 0, id_candidato, [0x00; 32])
+                .unwrap();
+            sistema
+                .votar_priv(id_votante2, 0, id_candidato, [0x00; 32])
+                .unwrap();
+            assert_eq!(
+                sistema.get_candidatos_priv(0, id_contrato).unwrap()[&id_candidato],
+                11
+            );
+            assert_eq!(
+                sistema.get_votos_por_cabeza_priv(0, id_contrato).unwrap()[&id_candidato],
+                2
+            );
+            assert!(sistema.get_votos_por_cabeza_priv(0, id_votante).is_err());
+
+            // la segunda vuelta mantiene los pesos y el voto en blanco tambien suma el peso
+            let id_segunda_vuelta =
+                sistema.crear_segunda_vuelta(0, vec![id_candidato], 1800000000000, 1900000000000);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1850000000000);
+            sistema
+                .votar_en_blanco_priv(id_votante, id_segunda_vuelta, [0x00; 32])
+                .unwrap();
+            assert_eq!(
+                sistema
+                    .get_votos_en_blanco_priv(id_segunda_vuelta, id_contrato)
+                    .unwrap(),
+                10
+            );
+            assert_eq!(
+                sistema
+                    .get_votos_en_blanco_por_cabeza_priv(id_segunda_vuelta, id_contrato)
+                    .unwrap(),
+                1
+            );
+
+            // el voto nulo suma el peso en los votos nulos y uno en los votantes que anularon
+            sistema
+                .votar_nulo_priv(id_votante2, id_segunda_vuelta, [0x00; 32])
+                .unwrap();
+            assert_eq!(
+                sistema
+                    .get_votos_nulos_priv(id_segunda_vuelta, id_contrato)
+                    .unwrap(),
+                1
+            );
+            assert_eq!(
+                sistema
+                    .get_votos_nulos_por_cabeza_priv(id_segunda_vuelta, id_contrato)
+                    .unwrap(),
+                1
+            );
+        }
+
+        #[ink::test]
+        fn test_votar_cuadratico_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            let id_contrato = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "presupuesto participativo".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
                     "Agustin".to_string(),
                     " ".to_string(),
-                    " ".to_string(),
-                    AccountId::from([0x02; 32])
+                    RolUsuario::Votante,
+                )
+                .unwrap();
+            for id in [id_candidato, id_candidato2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+                sistema.registrar_candidato_en_eleccion_priv(id, 0).unwrap();
+            }
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(
+                    id_admin,
+                    0,
+                    ModoEleccion::Cuadratico { creditos: 0 }
+                ),
+                Err(Error::ConfiguracionInvalida)
+            );
+            sistema
+                .set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Cuadratico { creditos: 10 })
+                .unwrap();
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            assert_eq!(
+                sistema.votar_priv(id_votante, 0, id_candidato, [0x00; 32]),
+                Err(Error::ModoEleccionInvalido)
+            );
+            // 3 votos cuestan 9 creditos y 2 votos cuestan 4, se pasa de 10
+            assert_eq!(
+                sistema.votar_cuadratico_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato, 3), (id_candidato2, 2)],
+                    [0x00; 32]
+                ),
+                Err(Error::CreditosInsuficientes)
+            );
+            assert_eq!(
+                sistema.votar_cuadratico_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato, 1), (id_candidato, 1)],
+                    [0x00; 32]
+                ),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_cuadratico_priv(id_votante, 0, vec![(id_admin, 1)], [0x00; 32]),
+                Err(Error::CandidatoNoExiste)
+            );
+            assert!(sistema
+                .votar_cuadratico_priv(id_admin, 0, vec![(id_candidato, 1)], [0x00; 32])
+                .is_err());
+            assert!(sistema
+                .votar_cuadratico_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato, 3), (id_candidato2, 1)],
+                    [0x00; 32]
+                )
+                .is_ok());
+            assert_eq!(
+                sistema.votar_cuadratico_priv(id_votante, 0, vec![(id_candidato, 1)], [0x00; 32]),
+                Err(Error::UsuarioYaRegistrado)
+            );
+
+            let candidatos = sistema.get_candidatos_priv(0, id_contrato).unwrap();
+            assert_eq!(candidatos[&id_candidato], 3);
+            assert_eq!(candidatos[&id_candidato2], 1);
+            let creditos_gastados = sistema.get_creditos_gastados_priv(0, id_contrato).unwrap();
+            assert_eq!(creditos_gastados[&id_candidato], 9);
+            assert_eq!(creditos_gastados[&id_candidato2], 1);
+            assert!(sistema.get_creditos_gastados_priv(0, id_votante).is_err());
+        }
+
+        #[ink::test]
+        fn test_votar_acumulativo_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_votante2 = AccountId::from([0x03; 32]);
+            let id_candidato = AccountId::from([0x04; 32]);
+            let id_candidato2 = AccountId::from([0x05; 32]);
+            let id_contrato = AccountId::from([0x06; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            for _ in 0..2 {
+                sistema
+                    .crear_eleccion_priv(
+                        id_admin,
+                        "directorio".to_string(),
+                        Fecha {
+                            dias: 1,
+                            mes: 1,
+                            anio: 2024,
+                        },
+                        Fecha {
+                            dias: 1,
+                            mes: 1,
+                            anio: 2025,
+                        },
+                    )
+                    .unwrap();
+            }
+            for id in [id_votante, id_votante2] {
+                sistema
+                    .registrar_usuario_priv(id, "".to_string(), "".to_string(), RolUsuario::Votante)
+                    .unwrap();
+            }
+            for id in [id_candidato, id_candidato2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+            }
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(
+                    id_admin,
+                    0,
+                    ModoEleccion::Acumulativo {
+                        votos_por_votante: 0,
+                        bancas: 1,
+                        exige_total: true
+                    }
+                ),
+                Err(Error::ConfiguracionInvalida)
+            );
+            for (id_eleccion, exige_total) in [(0, true), (1, false)] {
+                sistema
+                    .set_modo_eleccion_priv(
+                        id_admin,
+                        id_eleccion,
+                        ModoEleccion::Acumulativo {
+                            votos_por_votante: 3,
+                            bancas: 1,
+                            exige_total,
+                        },
+                    )
+                    .unwrap();
+                for id in [id_votante, id_votante2] {
+                    sistema
+                        .registrar_votante_en_eleccion_priv(id, id_eleccion)
+                        .unwrap();
+                }
+                for id in [id_candidato, id_candidato2] {
+                    sistema
+                        .registrar_candidato_en_eleccion_priv(id, id_eleccion)
+                        .unwrap();
+                }
+            }
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            // la eleccion 0 exige usar los 3 votos
+            assert_eq!(
+                sistema.votar_acumulativo_priv(id_votante, 0, vec![(id_candidato, 2)], [0x00; 32]),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_acumulativo_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato, 2), (id_candidato2, 2)],
+                    [0x00; 32]
+                ),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_acumulativo_priv(
+                    id_votante,
+                    0,
+                    vec![(id_candidato, 2), (id_candidato, 1)],
+                    [0x00; 32]
+                ),
+                Err(Error::BoletaInvalida)
+            );
+            assert!(sistema
+                .votar_acumulativo_priv(id_admin, 0, vec![(id_candidato, 3)], [0x00; 32])
+                .is_err());
+            assert!(sistema
+                .votar_acumulativo_priv(id_votante, 0, vec![(id_candidato, 3)], [0x00; 32])
+                .is_ok());
+            assert_eq!(
+                sistema.votar_acumulativo_priv(id_votante, 0, vec![(id_candidato, 3)], [0x00; 32]),
+                Err(Error::UsuarioYaRegistrado)
+            );
+            assert!(sistema
+                .votar_acumulativo_priv(
+                    id_votante2,
+                    0,
+                    vec![(id_candidato, 1), (id_candidato2, 2)],
+                    [0x00; 32]
+                )
+                .is_ok());
+            let candidatos = sistema.get_candidatos_priv(0, id_contrato).unwrap();
+            assert_eq!(candidatos[&id_candidato], 4);
+            assert_eq!(candidatos[&id_candidato2], 2);
+            assert_eq!(
+                sistema
+                    .get_boletas_acumulativas_priv(0, id_contrato)
+                    .unwrap()[&id_votante],
+                vec![(id_candidato, 3)]
+            );
+
+            // la eleccion 1 permite usar menos votos pero no ninguno ni mas de 3
+            assert_eq!(
+                sistema.votar_acumulativo_priv(id_votante, 1, Vec::new(), [0x00; 32]),
+                Err(Error::BoletaInvalida)
+            );
+            assert_eq!(
+                sistema.votar_acumulativo_priv(id_votante, 1, vec![(id_candidato, 4)], [0x00; 32]),
+                Err(Error::BoletaInvalida)
+            );
+            assert!(sistema
+                .votar_acumulativo_priv(id_votante, 1, vec![(id_candidato2, 2)], [0x00; 32])
+                .is_ok());
+            assert!(sistema
+                .get_boletas_acumulativas_priv(1, id_votante)
+                .is_err());
+        }
+
+        #[ink::test]
+        fn test_desempate_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_candidato1 = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            let id_candidato3 = AccountId::from([0x05; 32]);
+            let id_contrato = AccountId::from([0x06; 32]);
+            let fecha_inicio_desempate = Fecha {
+                dias: 10,
+                mes: 1,
+                anio: 2025,
+            };
+            let fecha_fin_desempate = Fecha {
+                dias: 11,
+                mes: 1,
+                anio: 2025,
+            };
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            for id_candidato in [id_candidato1, id_candidato2, id_candidato3] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+            }
+            for politica in [
+                PoliticaDesempate::DecisionAdmin,
+                PoliticaDesempate::SegundaVuelta,
+            ] {
+                sistema
+                    .crear_eleccion_priv(
+                        id_admin,
+                        "Presidente".to_string(),
+                        Fecha {
+                            dias: 1,
+                            mes: 1,
+                            anio: 2024,
+                        },
+                        Fecha {
+                            dias: 1,
+                            mes: 1,
+                            anio: 2025,
+                        },
+                    )
+                    .unwrap();
+                let id_eleccion = sistema.elecciones.len() as u64 - 1;
+                assert!(sistema
+                    .set_politica_desempate_priv(id_candidato1, id_eleccion, politica.clone())
+                    .is_err());
+
+                // en preferencial el primer lugar del conteo no es el resultado, no se puede desempatar
+                sistema
+                    .set_modo_eleccion_priv(id_admin, id_eleccion, ModoEleccion::Preferencial)
+                    .unwrap();
+                assert_eq!(
+                    sistema.set_politica_desempate_priv(id_admin, id_eleccion, politica.clone()),
+                    Err(Error::ModoEleccionInvalido)
+                );
+                sistema
+                    .set_modo_eleccion_priv(id_admin, id_eleccion, ModoEleccion::Mayoria)
+                    .unwrap();
+
+                sistema
+                    .set_politica_desempate_priv(id_admin, id_eleccion, politica)
+                    .unwrap();
+                assert_eq!(
+                    sistema.set_modo_eleccion_priv(
+                        id_admin,
+                        id_eleccion,
+                        ModoEleccion::Stv { bancas: 1 }
+                    ),
+                    Err(Error::ModoEleccionInvalido)
+                );
+                // el candidato 3 se registra primero
+                for id_candidato in [id_candidato3, id_candidato1, id_candidato2] {
+                    sistema
+                        .registrar_candidato_en_eleccion_priv(id_candidato, id_eleccion)
+                        .unwrap();
+                }
+            }
+            assert_eq!(
+                sistema.get_politica_desempate_priv(0, id_contrato).unwrap(),
+                PoliticaDesempate::DecisionAdmin
+            );
+            assert_eq!(
+                sistema.get_orden_candidatos_priv(0, id_contrato).unwrap(),
+                vec![id_candidato3, id_candidato1, id_candidato2]
+            );
+
+            assert_eq!(
+                sistema.desempatar_priv(id_admin, 0, id_candidato1),
+                Err(Error::EleccionAbierta)
+            );
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1800000000000);
+            assert_eq!(
+                sistema.set_politica_desempate_priv(id_admin, 0, PoliticaDesempate::DeclararEmpate),
+                Err(Error::EleccionAbierta)
+            );
+            assert_eq!(
+                sistema.desempatar_priv(id_admin, 0, id_candidato1),
+                Err(Error::SinEmpate)
+            );
+
+            for id_eleccion in 0..2 {
+                let candidatos = &mut sistema.elecciones[id_eleccion].candidatos;
+                candidatos.insert(id_candidato1, 10);
+                candidatos.insert(id_candidato2, 10);
+                candidatos.insert(id_candidato3, 3);
+            }
+
+            // la eleccion 0 la decide el admin entre los empatados
+            assert_eq!(
+                sistema.desempatar_priv(id_admin, 0, id_candidato3),
+                Err(Error::CandidatoNoExiste)
+            );
+            assert!(sistema
+                .desempatar_priv(id_candidato1, 0, id_candidato1)
+                .is_err());
+            assert_eq!(
+                sistema.crear_segunda_vuelta_por_empate_priv(
+                    id_admin,
+                    0,
+                    fecha_inicio_desempate.clone(),
+                    fecha_fin_desempate.clone()
+                ),
+                Err(Error::PoliticaDesempateInvalida)
+            );
+            assert!(sistema.desempatar_priv(id_admin, 0, id_candidato2).is_ok());
+            assert_eq!(
+                sistema.desempatar_priv(id_admin, 0, id_candidato1),
+                Err(Error::DesempateYaResuelto)
+            );
+            assert_eq!(
+                sistema.get_decision_desempate_priv(0, id_contrato).unwrap(),
+                Some(id_candidato2)
+            );
+
+            // la eleccion 1 va a segunda vuelta entre los empatados
+            assert_eq!(
+                sistema.desempatar_priv(id_admin, 1, id_candidato1),
+                Err(Error::PoliticaDesempateInvalida)
+            );
+            assert_eq!(
+                sistema.crear_segunda_vuelta_por_empate_priv(
+                    id_admin,
+                    1,
+                    fecha_inicio_desempate.clone(),
+                    fecha_inicio_desempate.clone()
+                ),
+                Err(Error::FechaInvalida)
+            );
+            assert_eq!(
+                sistema.crear_segunda_vuelta_por_empate_priv(
+                    id_admin,
+                    1,
+                    fecha_inicio_desempate.clone(),
+                    fecha_fin_desempate.clone()
+                ),
+                Ok(2)
+            );
+            assert_eq!(
+                sistema.crear_segunda_vuelta_por_empate_priv(
+                    id_admin,
+                    1,
+                    fecha_inicio_desempate.clone(),
+                    fecha_fin_desempate.clone()
+                ),
+                Err(Error::SegundaVueltaYaCreada)
+            );
+            assert_eq!(
+                sistema.get_segunda_vuelta_priv(1, id_contrato).unwrap(),
+                Some(2)
+            );
+            let segunda_vuelta = sistema.get_candidatos_priv(2, id_contrato).unwrap();
+            assert_eq!(segunda_vuelta.len(), 2);
+            assert!(!segunda_vuelta.contains_key(&id_candidato3));
+            assert!(sistema.get_segunda_vuelta_priv(1, id_admin).is_err());
+        }
+
+        #[ink::test]
+        fn test_promover_listas_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante = AccountId::from([0x02; 32]);
+            let id_candidato1 = AccountId::from([0x03; 32]);
+            let id_candidato2 = AccountId::from([0x04; 32]);
+            let id_candidato3 = AccountId::from([0x05; 32]);
+            let id_candidato4 = AccountId::from([0x06; 32]);
+            let id_contrato = AccountId::from([0x07; 32]);
+            let id_candidato5 = AccountId::from([0x08; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            // las primarias son la eleccion 0 y la general la 1
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "Diputados".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2024,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 6,
+                        anio: 2024,
+                    },
                 )
-                .is_ok());
-            assert!(sistema
-                .set_admin_priv(
-                    AccountId::from([0x01; 32]),
+                .unwrap();
+            sistema
+                .crear_eleccion_priv(
+                    id_admin,
+                    "Diputados".to_string(),
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2025,
+                    },
+                    Fecha {
+                        dias: 1,
+                        mes: 1,
+                        anio: 2026,
+                    },
+                )
+                .unwrap();
+            sistema
+                .registrar_usuario_priv(
+                    id_votante,
                     "Agustin".to_string(),
                     " ".to_string(),
-                    " ".to_string(),
-                    AccountId::from([0x03; 32])
+                    RolUsuario::Votante,
                 )
-                .is_err());
-        }
-
-        #[ink::test]
-        fn test_eleccion_activa() {
-            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
-            sistema.crear_eleccion_priv(
-                AccountId::from([0x01; 32]),
-                "cargo".to_string(),
-                Fecha {
-                    dias: 1,
-                    mes: 7,
-                    anio: 2024,
-                },
-                Fecha {
-                    dias: 3,
-                    mes: 7,
-                    anio: 2024,
-                },
+                .unwrap();
+            sistema
+                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .unwrap();
+            for id_candidato in [
+                id_candidato1,
+                id_candidato2,
+                id_candidato3,
+                id_candidato4,
+                id_candidato5,
+            ] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+            }
+            sistema
+                .set_modo_eleccion_priv(
+                    id_admin,
+                    1,
+                    ModoEleccion::Listas {
+                        bancas: 2,
+                        umbral: 3,
+                    },
+                )
+                .unwrap();
+            // la general exige paridad y el candidato 5 no declaro su genero
+            sistema.set_paridad_priv(id_admin, 1, true).unwrap();
+            for (id_candidato, genero) in [
+                (id_candidato1, Genero::Masculino),
+                (id_candidato2, Genero::Femenino),
+                (id_candidato3, Genero::Femenino),
+                (id_candidato4, Genero::Masculino),
+            ] {
+                sistema.set_genero_priv(id_candidato, genero).unwrap();
+            }
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(
+                    id_admin,
+                    0,
+                    ModoEleccion::Primarias {
+                        eleccion_general: 0
+                    }
+                ),
+                Err(Error::ConfiguracionInvalida)
             );
+            sistema
+                .set_modo_eleccion_priv(
+                    id_admin,
+                    0,
+                    ModoEleccion::Primarias {
+                        eleccion_general: 1,
+                    },
+                )
+                .unwrap();
 
-            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1719878400000);
-
-            assert!(sistema.eleccion_activa(0).is_ok());
-            assert_eq!(sistema.eleccion_activa(0).unwrap(), true);
-
-            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1800000000000);
-
-            assert!(sistema.eleccion_activa(0).is_ok());
-            assert_eq!(sistema.eleccion_activa(0).unwrap(), false);
-            assert!(sistema.eleccion_activa(1).is_err());
-        }
-        #[ink::test]
-        fn test_eleccion_cerrada() {
-            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
-            sistema.crear_eleccion_priv(
-                AccountId::from([0x01; 32]),
-                "cargo".to_string(),
-                Fecha {
-                    dias: 1,
-                    mes: 1,
-                    anio: 2023,
-                },
-                Fecha {
-                    dias: 1,
-                    mes: 2,
-                    anio: 2023,
-                },
+            assert_eq!(
+                sistema.registrar_lista_priv(id_admin, 0, "A1".to_string(), vec![id_candidato1]),
+                Err(Error::ModoEleccionInvalido)
+            );
+            assert_eq!(
+                sistema.registrar_lista_interna_priv(
+                    id_admin,
+                    1,
+                    "A".to_string(),
+                    "A1".to_string(),
+                    vec![id_candidato1]
+                ),
+                Err(Error::ModoEleccionInvalido)
             );
 
-            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1719878400000);
-
-            assert!(sistema.eleccion_cerrada(0).is_ok());
-            assert_eq!(sistema.eleccion_cerrada(0).unwrap(), true);
+            // las listas de las primarias tienen que cumplir la paridad de la general desde que se registran
+            assert_eq!(
+                sistema.registrar_lista_interna_priv(
+                    id_admin,
+                    0,
+                    "B".to_string(),
+                    "B1".to_string(),
+                    vec![id_candidato3, id_candidato5]
+                ),
+                Err(Error::ListaSinParidad)
+            );
+            sistema
+                .set_genero_priv(id_candidato5, Genero::Masculino)
+                .unwrap();
+            for (agrupacion, nombre, candidatos) in [
+                ("A", "A1", vec![id_candidato1]),
+                ("A", "A2", vec![id_candidato2]),
+                ("B", "B1", vec![id_candidato3, id_candidato5]),
+                ("C", "C1", vec![id_candidato4]),
+            ] {
+                sistema
+                    .registrar_lista_interna_priv(
+                        id_admin,
+                        0,
+                        agrupacion.to_string(),
+                        nombre.to_string(),
+                        candidatos,
+                    )
+                    .unwrap();
+            }
 
-            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(17198784000);
+            // el candidato 5 no puede cambiar su genero mientras la general exige paridad
+            assert_eq!(
+                sistema.set_genero_priv(id_candidato5, Genero::Femenino),
+                Err(Error::GeneroBloqueado)
+            );
+            // sin paridad lo puede cambiar, pero entonces la general no puede volver a exigirla
+            sistema.set_paridad_priv(id_admin, 1, false).unwrap();
+            sistema
+                .set_genero_priv(id_candidato5, Genero::Femenino)
+                .unwrap();
+            assert_eq!(
+                sistema.set_paridad_priv(id_admin, 1, true),
+                Err(Error::ListaSinParidad)
+            );
+            sistema
+                .set_genero_priv(id_candidato5, Genero::Masculino)
+                .unwrap();
+            sistema.set_paridad_priv(id_admin, 1, true).unwrap();
 
-            assert!(sistema.eleccion_cerrada(0).is_ok());
-            assert_eq!(sistema.eleccion_cerrada(0).unwrap(), false);
-            assert!(sistema.eleccion_cerrada(1).is_err());
-        }
-        #[ink::test]
-        fn test_eleccion_no_abierta() {
-            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
-            sistema.crear_eleccion_priv(
-                AccountId::from([0x01; 32]),
-                "cargo".to_string(),
-                Fecha {
-                    dias: 1,
-                    mes: 1,
-                    anio: 2023,
-                },
-                Fecha {
-                    dias: 1,
-                    mes: 2,
-                    anio: 2023,
-                },
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+            assert!(sistema
+                .votar_lista_priv(id_votante, 0, 1, [0x00; 32])
+                .is_ok());
+            assert_eq!(
+                sistema.promover_listas_priv(id_admin, 0),
+                Err(Error::EleccionAbierta)
             );
 
-            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1719878400000);
+            // sobre 200 votos validos la lista C1 no llega al 1,5% y en la agrupacion A gana A2
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1720000000000);
+            for (nro_lista, votos) in [(0, 40), (1, 50), (2, 30), (3, 1)] {
+                sistema.elecciones[0].listas[nro_lista].votos = votos;
+            }
+            sistema.elecciones[0].votos_en_blanco = 79;
 
-            assert!(sistema.eleccion_no_abierta(0).is_ok());
-            assert_eq!(sistema.eleccion_no_abierta(0).unwrap(), false);
+            // cerradas las primarias el candidato 5 cambia su genero y la lista B1 ya no cumple la paridad
+            sistema
+                .set_genero_priv(id_candidato5, Genero::Femenino)
+                .unwrap();
+            assert_eq!(
+                sistema.promover_listas_priv(id_admin, 0),
+                Err(Error::ListaSinParidad)
+            );
+            sistema
+                .set_genero_priv(id_candidato5, Genero::Masculino)
+                .unwrap();
 
-            ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(17198784000);
+            assert!(sistema.promover_listas_priv(id_votante, 0).is_err());
+            assert!(sistema.promover_listas_priv(id_admin, 0).is_ok());
+            assert_eq!(
+                sistema.promover_listas_priv(id_admin, 0),
+                Err(Error::ListasYaPromovidas)
+            );
 
-            assert!(sistema.eleccion_no_abierta(0).is_ok());
-            assert_eq!(sistema.eleccion_no_abierta(0).unwrap(), true);
-            assert!(sistema.eleccion_no_abierta(1).is_err());
+            assert_eq!(
+                sistema.get_listas_priv(1, id_contrato).unwrap(),
+                vec![
+                    Lista::new("A".to_string(), vec![id_candidato2], 0),
+                    Lista::new("B".to_string(), vec![id_candidato3, id_candidato5], 0),
+                ]
+            );
+            assert_eq!(sistema.mostrar_candidatos(1).unwrap().len(), 3);
         }
+
         #[ink::test]
-        fn test_crear_eleccion_priv() {
-            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
-            assert!(sistema
+        fn test_set_reglas_validez_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_contrato = AccountId::from([0x02; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
+            sistema
                 .crear_eleccion_priv(
-                    AccountId::from([0x01; 32]),
-                    "cargo".to_string(),
+                    id_admin,
+                    "comision directiva".to_string(),
                     Fecha {
                         dias: 1,
                         mes: 1,
-                        anio: 2021,
+                        anio: 2024,
                     },
                     Fecha {
                         dias: 1,
                         mes: 1,
-                        anio: 2022,
-                    }
+                        anio: 2025,
+                    },
                 )
-                .is_ok());
+                .unwrap();
+
+            // por defecto no hay quorum y alcanza la mayoria simple
+            assert_eq!(sistema.get_quorum_priv(0, id_contrato).unwrap(), 0);
+            assert_eq!(
+                sistema.get_mayoria_requerida_priv(0, id_contrato).unwrap(),
+                MayoriaRequerida::Simple
+            );
+
+            assert!(sistema
+                .set_reglas_validez_priv(id_contrato, 0, 50, MayoriaRequerida::Absoluta)
+                .is_err());
+            assert_eq!(
+                sistema.set_reglas_validez_priv(id_admin, 0, 101, MayoriaRequerida::Absoluta),
+                Err(Error::ConfiguracionInvalida)
+            );
             assert!(sistema
+                .set_reglas_validez_priv(id_admin, 0, 50, MayoriaRequerida::DosTercios)
+                .is_ok());
+            assert_eq!(sistema.get_quorum_priv(0, id_contrato).unwrap(), 50);
+            assert_eq!(
+                sistema.get_mayoria_requerida_priv(0, id_contrato).unwrap(),
+                MayoriaRequerida::DosTercios
+            );
+            assert!(sistema.get_quorum_priv(0, id_admin).is_err());
+
+            // con reglas de validez no se puede pasar a otro modo, y en otro modo no se pueden fijar
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Aprobacion),
+                Err(Error::ModoEleccionInvalido)
+            );
+            sistema
+                .set_reglas_validez_priv(id_admin, 0, 0, MayoriaRequerida::Simple)
+                .unwrap();
+            sistema
+                .set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Aprobacion)
+                .unwrap();
+            assert_eq!(
+                sistema.set_reglas_validez_priv(id_admin, 0, 50, MayoriaRequerida::DosTercios),
+                Err(Error::ModoEleccionInvalido)
+            );
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+            assert_eq!(
+                sistema.set_reglas_validez_priv(id_admin, 0, 10, MayoriaRequerida::Simple),
+                Err(Error::EleccionAbierta)
+            );
+        }
+
+        #[ink::test]
+        fn test_paridad_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_candidata1 = AccountId::from([0x02; 32]);
+            let id_candidato2 = AccountId::from([0x03; 32]);
+            let id_candidata3 = AccountId::from([0x04; 32]);
+            let id_candidato4 = AccountId::from([0x05; 32]);
+            let id_candidato5 = AccountId::from([0x06; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema
                 .crear_eleccion_priv(
-                    AccountId::from([0x02; 32]),
-                    "cargo".to_string(),
+                    id_admin,
+                    "Diputados".to_string(),
                     Fecha {
                         dias: 1,
                         mes: 1,
-                        anio: 2021,
+                        anio: 2024,
                     },
                     Fecha {
                         dias: 1,
                         mes: 1,
-                        anio: 2022,
-                    }
+                        anio: 2025,
+                    },
                 )
-                .is_err());
-        }
+                .unwrap();
+            assert_eq!(
+                sistema.set_genero_priv(id_candidata1, Genero::Femenino),
+                Err(Error::UsuarioNoRegistrado)
+            );
+            for (id_candidato, genero) in [
+                (id_candidata1, Genero::Femenino),
+                (id_candidato2, Genero::Masculino),
+                (id_candidata3, Genero::Femenino),
+                (id_candidato4, Genero::Masculino),
+                (id_candidato5, Genero::NoBinario),
+            ] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_candidato,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Candidato,
+                    )
+                    .unwrap();
+                sistema.set_genero_priv(id_candidato, genero).unwrap();
+            }
 
-        #[ink::test]
-        fn test_registar_usuario_priv() {
-            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
+            assert_eq!(
+                sistema.set_paridad_priv(id_admin, 0, true),
+                Err(Error::ModoEleccionInvalido)
+            );
+            sistema
+                .set_modo_eleccion_priv(
+                    id_admin,
+                    0,
+                    ModoEleccion::Listas {
+                        bancas: 4,
+                        umbral: 3,
+                    },
+                )
+                .unwrap();
+            assert!(sistema.set_paridad_priv(id_candidata1, 0, true).is_err());
+            assert!(sistema.set_paridad_priv(id_admin, 0, true).is_ok());
 
+            // dos mujeres seguidas no cumplen la paridad
+            assert_eq!(
+                sistema.registrar_lista_priv(
+                    id_admin,
+                    0,
+                    "A".to_string(),
+                    vec![id_candidata1, id_candidata3, id_candidato2]
+                ),
+                Err(Error::ListaSinParidad)
+            );
+            // una persona no binaria entre dos mujeres no alcanza, las mujeres y los varones se tienen que alternar
+            assert_eq!(
+                sistema.registrar_lista_priv(
+                    id_admin,
+                    0,
+                    "A".to_string(),
+                    vec![id_candidata1, id_candidato5, id_candidata3]
+                ),
+                Err(Error::ListaSinParidad)
+            );
             assert!(sistema
-                .registrar_usuario_priv(
-                    AccountId::from([0x02; 32]),
-                    "Agustin".to_string(),
-                    " ".to_string(),
-                    RolUsuario::Votante
+                .registrar_lista_priv(
+                    id_admin,
+                    0,
+                    "A".to_string(),
+                    vec![id_candidata1, id_candidato5, id_candidato2]
                 )
                 .is_ok());
             assert!(sistema
-                .registrar_usuario_priv(
-                    AccountId::from([0x02; 32]),
-                    "Agustin".to_string(),
-                    " ".to_string(),
-                    RolUsuario::Votante
+                .registrar_lista_priv(
+                    id_admin,
+                    0,
+                    "B".to_string(),
+                    vec![id_candidato4, id_candidata3]
                 )
-                .is_err());
+                .is_ok());
+
+            // la candidata 3 ya esta en una lista con paridad y no puede cambiar su genero
+            assert_eq!(
+                sistema.set_genero_priv(id_candidata3, Genero::Masculino),
+                Err(Error::GeneroBloqueado)
+            );
+
+            // sin paridad exigida el genero se puede cambiar, pero la lista B ya no cumple y no se puede volver a exigir
+            assert!(sistema.set_paridad_priv(id_admin, 0, false).is_ok());
             assert!(sistema
-                .registrar_usuario_priv(
-                    AccountId::from([0x01; 32]),
-                    "Agustin".to_string(),
-                    " ".to_string(),
-                    RolUsuario::Votante
-                )
-                .is_err());
+                .set_genero_priv(id_candidata3, Genero::Masculino)
+                .is_ok());
+            assert_eq!(
+                sistema.set_paridad_priv(id_admin, 0, true),
+                Err(Error::ListaSinParidad)
+            );
         }
+
         #[ink::test]
-        fn test_registrar_votante_en_eleccion_priv() {
-            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
-            sistema
-                .registrar_usuario_priv(
-                    AccountId::from([0x02; 32]),
-                    "Agustin".to_string(),
-                    " ".to_string(),
-                    RolUsuario::Votante,
-                )
-                .unwrap();
+        fn test_votar_por_distrito_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante1 = AccountId::from([0x02; 32]);
+            let id_votante2 = AccountId::from([0x03; 32]);
+            let id_candidato = AccountId::from([0x04; 32]);
+            let id_contrato = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
             sistema
                 .crear_eleccion_priv(
-                    AccountId::from([0x01; 32]),
-                    "cargo".to_string(),
+                    id_admin,
+                    "Presidente".to_string(),
                     Fecha {
                         dias: 1,
                         mes: 1,
-                        anio: 2021,
+                        anio: 2024,
                     },
                     Fecha {
                         dias: 1,
                         mes: 1,
-                        anio: 2022,
+                        anio: 2025,
                     },
                 )
                 .unwrap();
+            for id_votante in [id_votante1, id_votante2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_votante,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Votante,
+                    )
+                    .unwrap();
+                sistema
+                    .registrar_votante_en_eleccion_priv(id_votante, 0)
+                    .unwrap();
+            }
+            sistema
+                .registrar_usuario_priv(
+                    id_candidato,
+                    "".to_string(),
+                    "".to_string(),
+                    RolUsuario::Candidato,
+                )
+                .unwrap();
+            sistema
+                .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                .unwrap();
+
             assert!(sistema
-                .registrar_votante_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
+                .agregar_distrito_priv(id_votante1, 0, "Norte".to_string(), 3)
+                .is_err());
+            assert_eq!(
+                sistema.agregar_distrito_priv(id_admin, 0, "Norte".to_string(), 0),
+                Err(Error::ConfiguracionInvalida)
+            );
+            assert!(sistema
+                .agregar_distrito_priv(id_admin, 0, "Norte".to_string(), 3)
                 .is_ok());
             assert!(sistema
-                .registrar_votante_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
-                .is_err());
-
+                .agregar_distrito_priv(id_admin, 0, "Sur".to_string(), 2)
+                .is_ok());
+            assert_eq!(
+                sistema.asignar_distrito_priv(id_admin, 0, id_votante1, 2),
+                Err(Error::DistritoNoExiste)
+            );
+            assert_eq!(
+                sistema.asignar_distrito_priv(id_admin, 0, id_candidato, 0),
+                Err(Error::UsuarioNoVotante)
+            );
             assert!(sistema
-                .registrar_votante_en_eleccion_priv(AccountId::from([0x02; 32]), 1)
-                .is_err(),);
+                .asignar_distrito_priv(id_admin, 0, id_votante1, 1)
+                .is_ok());
             assert!(sistema
-                .registrar_votante_en_eleccion_priv(AccountId::from([0x01; 32]), 0)
-                .is_err());
+                .set_regla_distrital_priv(id_admin, 0, ReglaDistrital::Proporcional)
+                .is_ok());
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Aprobacion),
+                Err(Error::ModoEleccionInvalido)
+            );
 
-            // setear timestamp par que la eleccion este abierta y no se pueda registrar votante
             set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+
+            // el votante 2 no tiene distrito y no puede votar
+            assert_eq!(
+                sistema.votar_priv(id_votante2, 0, id_candidato, [0x00; 32]),
+                Err(Error::VotanteSinDistrito)
+            );
+            assert_eq!(
+                sistema.votar_en_blanco_priv(id_votante2, 0, [0x00; 32]),
+                Err(Error::VotanteSinDistrito)
+            );
             assert!(sistema
-                .registrar_votante_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
-                .is_err());
+                .votar_priv(id_votante1, 0, id_candidato, [0x00; 32])
+                .is_ok());
+
+            let distritos = sistema.get_distritos_priv(0, id_contrato).unwrap();
+            assert_eq!(distritos[0].candidatos().get(&id_candidato), None);
+            assert_eq!(distritos[1].candidatos()[&id_candidato], 1);
+            assert_eq!(distritos[1].electores(), 2);
+            assert_eq!(
+                sistema.get_regla_distrital_priv(0, id_contrato).unwrap(),
+                ReglaDistrital::Proporcional
+            );
+            assert!(sistema.get_distritos_priv(0, id_admin).is_err());
         }
+
         #[ink::test]
-        fn test_registrar_candidato_en_eleccion_priv() {
-            let mut sistema = SistemaVotacion::new_priv(AccountId::from([0x01; 32]));
-            sistema.registrar_usuario_priv(
-                AccountId::from([0x02; 32]),
-                "Agustin".to_string(),
-                " ".to_string(),
-                RolUsuario::Candidato,
-            );
+        fn test_voto_secreto_priv() {
+            let id_admin = AccountId::from([0x01; 32]);
+            let id_votante1 = AccountId::from([0x02; 32]);
+            let id_votante2 = AccountId::from([0x03; 32]);
+            let id_candidato = AccountId::from([0x04; 32]);
+            let id_contrato = AccountId::from([0x05; 32]);
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
+            let mut sistema = SistemaVotacion::new_priv(id_admin);
+            sistema.set_id_contrato(id_contrato).unwrap();
             sistema
                 .crear_eleccion_priv(
-                    AccountId::from([0x01; 32]),
-                    "cargo".to_string(),
+                    id_admin,
+                    "Presidente".to_string(),
                     Fecha {
                         dias: 1,
                         mes: 1,
-                        anio: 2021,
+                        anio: 2024,
                     },
                     Fecha {
                         dias: 1,
                         mes: 1,
-                        anio: 2022,
+                        anio: 2025,
                     },
                 )
                 .unwrap();
+            for id_votante in [id_votante1, id_votante2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_votante,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Votante,
+                    )
+                    .unwrap();
+            }
+            sistema
+                .registrar_usuario_priv(
+                    id_candidato,
+                    "".to_string(),
+                    "".to_string(),
+                    RolUsuario::Candidato,
+                )
+                .unwrap();
+            sistema
+                .registrar_candidato_en_eleccion_priv(id_candidato, 0)
+                .unwrap();
 
+            // el revelado tiene que terminar despues del cierre
+            assert_eq!(
+                sistema.set_voto_secreto_priv(
+                    id_admin,
+                    0,
+                    Some(Fecha {
+                        dias: 1,
+                        mes: 6,
+                        anio: 2024,
+                    })
+                ),
+                Err(Error::FechaInvalida)
+            );
             assert!(sistema
-                .registrar_candidato_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
+                .set_voto_secreto_priv(
+                    id_admin,
+                    0,
+                    Some(Fecha {
+                        dias: 1,
+                        mes: 2,
+                        anio: 2025,
+                    })
+                )
                 .is_ok());
-            assert!(sistema
-                .registrar_candidato_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
-                .is_err());
-            assert!(sistema
-                .registrar_candidato_en_eleccion_priv(AccountId::from([0x01; 32]), 0)
-                .is_err());
-            assert!(sistema
-                .registrar_candidato_en_eleccion_priv(AccountId::from([0x02; 32]), 1)
-                .is_err());
+            assert_eq!(
+                sistema.set_modo_eleccion_priv(id_admin, 0, ModoEleccion::Borda),
+                Err(Error::ModoEleccionInvalido)
+            );
 
             set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+            let sal = [0x07; 32];
+            assert_eq!(
+                sistema.votar_priv(id_votante1, 0, id_candidato, [0x00; 32]),
+                Err(Error::VotoSecreto)
+            );
+            assert_eq!(
+                sistema.votar_en_blanco_priv(id_votante1, 0, [0x00; 32]),
+                Err(Error::VotoSecreto)
+            );
+            assert_eq!(
+                sistema.votar_nulo_priv(id_votante1, 0, [0x00; 32]),
+                Err(Error::VotoSecreto)
+            );
+            assert_eq!(
+                sistema.votar_candidato_escrito_priv(
+                    id_votante1,
+                    0,
+                    "Juan".to_string(),
+                    [0x00; 32]
+                ),
+                Err(Error::VotoSecreto)
+            );
             assert!(sistema
-                .registrar_candidato_en_eleccion_priv(AccountId::from([0x02; 32]), 0)
-                .is_err());
+                .comprometer_voto_priv(
+                    id_votante1,
+                    0,
+                    calcular_compromiso(0, id_votante1, id_candidato, sal)
+                )
+                .is_ok());
+            assert_eq!(
+                sistema.comprometer_voto_priv(
+                    id_votante1,
+                    0,
+                    calcular_compromiso(0, id_votante1, id_candidato, sal)
+                ),
+                Err(Error::UsuarioYaRegistrado)
+            );
+            assert!(sistema
+                .comprometer_voto_priv(
+                    id_votante2,
+                    0,
+                    calcular_compromiso(0, id_votante1, id_candidato, sal)
+                )
+                .is_ok());
+
+            // mientras la eleccion esta abierta no se ve a quien se voto
+            assert_eq!(
+                sistema.revelar_voto_priv(id_votante1, 0, id_candidato, sal),
+                Err(Error::EleccionAbierta)
+            );
+            assert_eq!(
+                sistema.get_candidatos_priv(0, id_contrato).unwrap()[&id_candidato],
+                0
+            );
+
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1736000000000);
+            assert_eq!(
+                sistema.revelar_voto_priv(id_votante1, 0, id_candidato, [0x08; 32]),
+                Err(Error::CompromisoInvalido)
+            );
+            // el votante 2 copio el compromiso del votante 1 y no lo puede revelar aunque conozca el candidato y la sal
+            assert_eq!(
+                sistema.revelar_voto_priv(id_votante2, 0, id_candidato, sal),
+                Err(Error::CompromisoInvalido)
+            );
+            let recibo = sistema
+                .revelar_voto_priv(id_votante1, 0, id_candidato, sal)
+                .unwrap();
+            assert_eq!(sistema.verificar_recibo(0, recibo), Ok(true));
+            assert_eq!(
+                sistema.revelar_voto_priv(id_votante1, 0, id_candidato, sal),
+                Err(Error::CompromisoNoExiste)
+            );
+            assert_eq!(
+                sistema.get_candidatos_priv(0, id_contrato).unwrap()[&id_candidato],
+                1
+            );
+
+            // pasado fin_revelado el compromiso del votante 2 queda sin revelar
+            set_block_timestamp::<ink_env::DefaultEnvironment>(1800000000000);
+            assert_eq!(
+                sistema.revelar_voto_priv(id_votante2, 0, id_candidato, sal),
+                Err(Error::EleccionNoActiva)
+            );
+            assert_eq!(
+                sistema
+                    .get_compromisos_sin_revelar_priv(0, id_contrato)
+                    .unwrap(),
+                vec![id_votante2]
+            );
+            assert_eq!(
+                sistema.get_fin_revelado_priv(0, id_contrato).unwrap(),
+                Some(1738368000000)
+            );
         }
+
         #[ink::test]
-        fn test_votar_priv() {
+        fn test_verificar_recibo() {
             let id_admin = AccountId::from([0x01; 32]);
-            let id_votante = AccountId::from([0x02; 32]);
-            let id_candidato = AccountId::from([0x03; 32]);
+            let id_votante1 = AccountId::from([0x02; 32]);
+            let id_votante2 = AccountId::from([0x03; 32]);
+            let id_candidato = AccountId::from([0x04; 32]);
             set_block_timestamp::<ink_env::DefaultEnvironment>(1672531200000);
             let mut sistema = SistemaVotacion::new_priv(id_admin);
             sistema
                 .crear_eleccion_priv(
                     id_admin,
-                    "cargo".to_string(),
+                    "Presidente".to_string(),
                     Fecha {
                         dias: 1,
                         mes: 1,
@@ -982,38 +6688,66 @@ mod sistema_votacion {
                     },
                 )
                 .unwrap();
-            sistema.registrar_usuario_priv(
-                id_votante,
-                "Agustin".to_string(),
-                " ".to_string(),
-                RolUsuario::Votante,
-            );
-            sistema.registrar_usuario_priv(
-                id_candidato,
-                "".to_string(),
-                "".to_string(),
-                RolUsuario::Candidato,
-            );
-
+            for id_votante in [id_votante1, id_votante2] {
+                sistema
+                    .registrar_usuario_priv(
+                        id_votante,
+                        "".to_string(),
+                        "".to_string(),
+                        RolUsuario::Votante,
+                    )
+                    .unwrap();
+            }
             sistema
-                .registrar_votante_en_eleccion_priv(id_votante, 0)
+                .registrar_usuario_priv(
+                    id_candidato,
+                    "".to_string(),
+                    "".to_string(),
+                    RolUsuario::Candidato,
+                )
                 .unwrap();
-
             sistema
                 .registrar_candidato_en_eleccion_priv(id_candidato, 0)
                 .unwrap();
 
-            assert!(sistema.votar_priv(id_votante, 0, id_candidato).is_err());
-
             set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
+            let recibo1 = sistema
+                .votar_priv(id_votante1, 0, id_candidato, [0x00; 32])
+                .unwrap();
+            let recibo2 = sistema
+                .votar_priv(id_votante2, 0, id_candidato, [0x00; 32])
+                .unwrap();
 
-            assert!(sistema
-                .votar_priv(id_votante, 0, AccountId::from([0x09; 32]))
-                .is_err());
-
-            assert!(sistema.votar_priv(id_admin, 0, id_candidato).is_err());
-            assert!(sistema.votar_priv(id_votante, 0, id_candidato).is_ok());
-            assert!(sistema.votar_priv(id_votante, 0, id_candidato).is_err());
+            // el mismo voto con la misma sal da otro recibo porque tiene otro numero de voto
+            assert_ne!(recibo1, recibo2);
+            assert_eq!(
+                recibo1,
+                calcular_recibo(0, 0, "candidato", &id_candidato, [0x00; 32])
+            );
+            assert_eq!(
+                recibo2,
+                calcular_recibo(0, 1, "candidato", &id_candidato, [0x00; 32])
+            );
+            assert_eq!(sistema.verificar_recibo(0, recibo1), Ok(true));
+            assert_eq!(sistema.verificar_recibo(0, recibo2), Ok(true));
+
+            // un recibo con otro tipo de boleta no se conto
+            let otro_tipo = calcular_recibo(0, 0, "escrito", &id_candidato, [0x00; 32]);
+            assert_eq!(sistema.verificar_recibo(0, otro_tipo), Ok(false));
+
+            // un recibo repetido se rechaza antes de sumar el voto
+            let eleccion = &mut sistema.elecciones[0];
+            eleccion
+                .recibos
+                .push(calcular_recibo(0, 2, "blanco", &(), [0x00; 32]));
+            assert_eq!(
+                eleccion.recibo_del_voto("blanco", &(), [0x00; 32]),
+                Err(Error::ReciboDuplicado)
+            );
+            assert_eq!(
+                sistema.verificar_recibo(1, recibo1),
+                Err(Error::EleccionNoExiste)
+            );
         }
 
         #[ink::test]
@@ -1111,6 +6845,7 @@ mod sistema_votacion {
                 nombre: "Agustin".to_string(),
                 email: " ".to_string(),
                 rol: RolUsuario::Votante,
+                genero: None,
             });
             assert_eq!(sistema.get_votantes_priv(0, id_contrato).unwrap(), vec);
 
@@ -1169,7 +6904,9 @@ mod sistema_votacion {
                 .unwrap();
 
             ink_env::test::set_block_timestamp::<ink_env::DefaultEnvironment>(1706745600000);
-            sistema.votar_priv(id_votante, 0, id_candidato).unwrap();
+            sistema
+                .votar_priv(id_votante, 0, id_candidato, [0x00; 32])
+                .unwrap();
 
             let mut vec = Vec::new();
             vec.push(Usuario {
@@ -1177,6 +6914,7 @@ mod sistema_votacion {
                 nombre: "Agustin".to_string(),
                 email: " ".to_string(),
                 rol: RolUsuario::Votante,
+                genero: None,
             });
 
             assert!(sistema
@@ -1220,6 +6958,7 @@ mod sistema_votacion {
                 nombre: "Agustin".to_string(),
                 email: " ".to_string(),
                 rol: RolUsuario::Votante,
+                genero: None,
             });
 
             assert!(sistema.get_usuarios_priv(id_contrato).is_ok());
@@ -1342,6 +7081,59 @@ mod sistema_votacion {
                 (Error::UsuarioNoCandidato, "El usuario no es candidato"),
                 (Error::FechaInvalida, "Fecha inválida"),
                 (Error::Overflow, "Overflow"),
+                (
+                    Error::ModoEleccionInvalido,
+                    "La operación no corresponde al modo de la elección",
+                ),
+                (Error::BoletaInvalida, "Boleta inválida"),
+                (
+                    Error::ConfiguracionInvalida,
+                    "Configuración de la elección inválida",
+                ),
+                (Error::ListaNoExiste, "La lista no existe"),
+                (Error::SinVotos, "La elección no tiene votos"),
+                (
+                    Error::SegundaVueltaYaCreada,
+                    "La segunda vuelta ya fue creada",
+                ),
+                (Error::OpcionNoExiste, "La opción no existe"),
+                (Error::CategoriaNoExiste, "La categoría no existe"),
+                (
+                    Error::CandidatosEscritosNoPermitidos,
+                    "La elección no permite candidatos escritos",
+                ),
+                (Error::CreditosInsuficientes, "Créditos insuficientes"),
+                (
+                    Error::PoliticaDesempateInvalida,
+                    "La política de desempate no permite la operación",
+                ),
+                (Error::SinEmpate, "La elección no tiene empate"),
+                (Error::DesempateYaResuelto, "El empate ya fue resuelto"),
+                (Error::ListasYaPromovidas, "Las listas ya fueron promovidas"),
+                (Error::UsuarioNoRegistrado, "El usuario no está registrado"),
+                (
+                    Error::ListaSinParidad,
+                    "La lista no cumple la paridad de género",
+                ),
+                (
+                    Error::GeneroBloqueado,
+                    "El género no se puede cambiar estando en una lista",
+                ),
+                (Error::DistritoNoExiste, "El distrito no existe"),
+                (
+                    Error::VotanteSinDistrito,
+                    "El votante no tiene distrito asignado",
+                ),
+                (Error::VotoSecreto, "La elección tiene voto secreto"),
+                (
+                    Error::CompromisoNoExiste,
+                    "El votante no comprometió su voto",
+                ),
+                (
+                    Error::CompromisoInvalido,
+                    "El voto no coincide con el compromiso",
+                ),
+                (Error::ReciboDuplicado, "El recibo del voto ya existe"),
             ];
 
             // Itera sobre cada caso de prueba